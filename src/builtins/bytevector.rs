@@ -0,0 +1,241 @@
+use std::cell::Ref;
+
+use crate::object::{Arity, Bytevector, Native, Object};
+
+use super::{expect_index, expect_range, expect_target};
+
+pub static NATIVES: &[Native] = &[
+    Native {
+        name: "bytevector?",
+        arity: Arity::Exactly(1),
+        func: is_bytevector,
+    },
+    Native {
+        name: "make-bytevector",
+        arity: Arity::Between(1, 2),
+        func: make_bytevector,
+    },
+    Native {
+        name: "bytevector",
+        arity: Arity::AtLeast(0),
+        func: bytevector,
+    },
+    Native {
+        name: "bytevector-length",
+        arity: Arity::Exactly(1),
+        func: bytevector_length,
+    },
+    Native {
+        name: "bytevector-u8-ref",
+        arity: Arity::Exactly(2),
+        func: u8_ref,
+    },
+    Native {
+        name: "bytevector-u8-set!",
+        arity: Arity::Exactly(3),
+        func: u8_set,
+    },
+    Native {
+        name: "bytevector-copy",
+        arity: Arity::Between(1, 3),
+        func: bytevector_copy,
+    },
+    Native {
+        name: "bytevector-copy!",
+        arity: Arity::Between(3, 5),
+        func: bytevector_copy_to,
+    },
+    Native {
+        name: "bytevector-append",
+        arity: Arity::AtLeast(0),
+        func: bytevector_append,
+    },
+    Native {
+        name: "utf8->string",
+        arity: Arity::Between(1, 3),
+        func: utf8_to_string,
+    },
+    Native {
+        name: "string->utf8",
+        arity: Arity::Between(1, 3),
+        func: string_to_utf8,
+    },
+];
+
+/// Borrows the bytes of a bytevector
+fn expect_bytevector<'a>(name: &str, obj: &'a Object) -> Result<Ref<'a, Vec<u8>>, String> {
+    match obj {
+        Object::Bytevector(bytevector) => Ok(bytevector.bytes.borrow()),
+        _ => Err(format!(
+            "{} expects a bytevector, but was given {}",
+            name, obj
+        )),
+    }
+}
+
+fn expect_mutable_bytevector<'a>(name: &str, obj: &'a Object) -> Result<&'a Bytevector, String> {
+    match obj {
+        Object::Bytevector(bytevector) if bytevector.mutable => Ok(bytevector),
+        Object::Bytevector(_) => Err(format!("{} cannot mutate the constant {}", name, obj)),
+        _ => Err(format!(
+            "{} expects a bytevector, but was given {}",
+            name, obj
+        )),
+    }
+}
+
+fn expect_byte(name: &str, obj: &Object) -> Result<u8, String> {
+    match obj {
+        Object::Integer(n) if (0..=255).contains(n) => Ok(*n as u8),
+        _ => Err(format!("{} expects a byte, but was given {}", name, obj)),
+    }
+}
+
+/// Copies the bytes of the bytevector in `args[0]`, between the optional start and end in `args[1..]`
+fn expect_slice(name: &str, args: &[Object]) -> Result<Vec<u8>, String> {
+    let bytes = expect_bytevector(name, &args[0])?;
+    let (start, end) = expect_range(name, args.get(1), args.get(2), bytes.len())?;
+
+    Ok(bytes[start..end].to_vec())
+}
+
+fn is_bytevector(args: &[Object]) -> Result<Object, String> {
+    Ok(Object::Bool(matches!(args[0], Object::Bytevector(_))))
+}
+
+fn make_bytevector(args: &[Object]) -> Result<Object, String> {
+    let len = match &args[0] {
+        Object::Integer(len) if *len >= 0 => *len as usize,
+        other => {
+            return Err(format!(
+                "make-bytevector expects a length, but was given {}",
+                other
+            ))
+        }
+    };
+    let fill = match args.get(1) {
+        Some(fill) => expect_byte("make-bytevector", fill)?,
+        None => 0,
+    };
+
+    Ok(Object::bytevector(vec![fill; len]))
+}
+
+fn bytevector(args: &[Object]) -> Result<Object, String> {
+    args.iter()
+        .map(|arg| expect_byte("bytevector", arg))
+        .collect::<Result<Vec<u8>, String>>()
+        .map(Object::bytevector)
+}
+
+fn bytevector_length(args: &[Object]) -> Result<Object, String> {
+    let bytes = expect_bytevector("bytevector-length", &args[0])?;
+    Ok(Object::Integer(bytes.len() as i64))
+}
+
+fn u8_ref(args: &[Object]) -> Result<Object, String> {
+    let bytes = expect_bytevector("bytevector-u8-ref", &args[0])?;
+    let index = expect_index("bytevector-u8-ref", &args[1], bytes.len())?;
+
+    Ok(Object::Integer(bytes[index] as i64))
+}
+
+fn u8_set(args: &[Object]) -> Result<Object, String> {
+    let bytevector = expect_mutable_bytevector("bytevector-u8-set!", &args[0])?;
+    let byte = expect_byte("bytevector-u8-set!", &args[2])?;
+    let mut bytes = bytevector.bytes.borrow_mut();
+    let index = expect_index("bytevector-u8-set!", &args[1], bytes.len())?;
+
+    bytes[index] = byte;
+    Ok(Object::Unspecified)
+}
+
+fn bytevector_copy(args: &[Object]) -> Result<Object, String> {
+    Ok(Object::bytevector(expect_slice("bytevector-copy", args)?))
+}
+
+/// (bytevector-copy! to at from [start [end]]) copies a part of `from` into `to`, starting at index `at`
+fn bytevector_copy_to(args: &[Object]) -> Result<Object, String> {
+    let to = expect_mutable_bytevector("bytevector-copy!", &args[0])?;
+    let from = expect_slice("bytevector-copy!", &args[2..])?;
+
+    let mut bytes = to.bytes.borrow_mut();
+    let target = expect_target("bytevector-copy!", &args[1], from.len(), bytes.len())?;
+
+    bytes[target].copy_from_slice(&from);
+    Ok(Object::Unspecified)
+}
+
+fn bytevector_append(args: &[Object]) -> Result<Object, String> {
+    let mut bytes = Vec::new();
+
+    for arg in args {
+        bytes.extend_from_slice(&expect_bytevector("bytevector-append", arg)?);
+    }
+
+    Ok(Object::bytevector(bytes))
+}
+
+fn utf8_to_string(args: &[Object]) -> Result<Object, String> {
+    let bytes = expect_slice("utf8->string", args)?;
+
+    match String::from_utf8(bytes) {
+        Ok(s) => Ok(Object::string(s)),
+        Err(e) => Err(format!("utf8->string: invalid UTF-8, {}", e.utf8_error())),
+    }
+}
+
+fn string_to_utf8(args: &[Object]) -> Result<Object, String> {
+    let chars: Vec<char> = match &args[0] {
+        Object::String(s) => s.chars.borrow().chars().collect(),
+        other => {
+            return Err(format!(
+                "string->utf8 expects a string, but was given {}",
+                other
+            ))
+        }
+    };
+    let (start, end) = expect_range("string->utf8", args.get(1), args.get(2), chars.len())?;
+
+    let s: String = chars[start..end].iter().collect();
+    Ok(Object::bytevector(s.into_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bytes_are_checked() {
+        assert_eq!(
+            bytevector(&[Object::Integer(0), Object::Integer(255)]),
+            Ok(Object::bytevector(vec![0, 255]))
+        );
+        assert_eq!(
+            bytevector(&[Object::Integer(256)]),
+            Err("bytevector expects a byte, but was given 256".to_string())
+        );
+        assert!(make_bytevector(&[Object::Integer(2), Object::Integer(-1)]).is_err());
+    }
+
+    #[test]
+    fn utf8_conversions() {
+        assert_eq!(
+            string_to_utf8(&[Object::string("aλ")]),
+            Ok(Object::bytevector(vec![0x61, 0xce, 0xbb]))
+        );
+        assert_eq!(
+            string_to_utf8(&[
+                Object::string("aλb"),
+                Object::Integer(1),
+                Object::Integer(2)
+            ]),
+            Ok(Object::bytevector(vec![0xce, 0xbb]))
+        );
+        assert_eq!(
+            utf8_to_string(&[Object::bytevector(vec![0x61, 0xce, 0xbb])]),
+            Ok(Object::string("aλ"))
+        );
+        assert!(utf8_to_string(&[Object::bytevector(vec![0xce])]).is_err());
+    }
+}
@@ -0,0 +1,354 @@
+use crate::object::{Arity, Native, Object};
+
+pub static NATIVES: &[Native] = &[
+    Native {
+        name: "char?",
+        arity: Arity::Exactly(1),
+        func: is_char,
+    },
+    Native {
+        name: "char->integer",
+        arity: Arity::Exactly(1),
+        func: char_to_integer,
+    },
+    Native {
+        name: "integer->char",
+        arity: Arity::Exactly(1),
+        func: integer_to_char,
+    },
+    Native {
+        name: "char=?",
+        arity: Arity::AtLeast(2),
+        func: eq,
+    },
+    Native {
+        name: "char<?",
+        arity: Arity::AtLeast(2),
+        func: lt,
+    },
+    Native {
+        name: "char>?",
+        arity: Arity::AtLeast(2),
+        func: gt,
+    },
+    Native {
+        name: "char<=?",
+        arity: Arity::AtLeast(2),
+        func: le,
+    },
+    Native {
+        name: "char>=?",
+        arity: Arity::AtLeast(2),
+        func: ge,
+    },
+    Native {
+        name: "char-ci=?",
+        arity: Arity::AtLeast(2),
+        func: eq_ci,
+    },
+    Native {
+        name: "char-ci<?",
+        arity: Arity::AtLeast(2),
+        func: lt_ci,
+    },
+    Native {
+        name: "char-ci>?",
+        arity: Arity::AtLeast(2),
+        func: gt_ci,
+    },
+    Native {
+        name: "char-ci<=?",
+        arity: Arity::AtLeast(2),
+        func: le_ci,
+    },
+    Native {
+        name: "char-ci>=?",
+        arity: Arity::AtLeast(2),
+        func: ge_ci,
+    },
+    Native {
+        name: "char-upcase",
+        arity: Arity::Exactly(1),
+        func: upcase,
+    },
+    Native {
+        name: "char-downcase",
+        arity: Arity::Exactly(1),
+        func: downcase,
+    },
+    Native {
+        name: "char-foldcase",
+        arity: Arity::Exactly(1),
+        func: foldcase,
+    },
+    Native {
+        name: "char-alphabetic?",
+        arity: Arity::Exactly(1),
+        func: is_alphabetic,
+    },
+    Native {
+        name: "char-numeric?",
+        arity: Arity::Exactly(1),
+        func: is_numeric,
+    },
+    Native {
+        name: "char-whitespace?",
+        arity: Arity::Exactly(1),
+        func: is_whitespace,
+    },
+    Native {
+        name: "char-upper-case?",
+        arity: Arity::Exactly(1),
+        func: is_upper_case,
+    },
+    Native {
+        name: "char-lower-case?",
+        arity: Arity::Exactly(1),
+        func: is_lower_case,
+    },
+    Native {
+        name: "digit-value",
+        arity: Arity::Exactly(1),
+        func: digit_value,
+    },
+];
+
+/// The zero of every run of ten decimal digits (general category Nd) in Unicode
+const DIGIT_ZEROS: &[u32] = &[
+    0x0030, 0x0660, 0x06F0, 0x07C0, 0x0966, 0x09E6, 0x0A66, 0x0AE6, 0x0B66, 0x0BE6, 0x0C66, 0x0CE6,
+    0x0D66, 0x0DE6, 0x0E50, 0x0ED0, 0x0F20, 0x1040, 0x1090, 0x17E0, 0x1810, 0x1946, 0x19D0, 0x1A80,
+    0x1A90, 0x1B50, 0x1BB0, 0x1C40, 0x1C50, 0xA620, 0xA8D0, 0xA900, 0xA9D0, 0xA9F0, 0xAA50, 0xABF0,
+    0xFF10, 0x104A0, 0x10D30, 0x11066, 0x110F0, 0x11136, 0x111D0, 0x112F0, 0x11450, 0x114D0,
+    0x11650, 0x116C0, 0x11730, 0x118E0, 0x11950, 0x11C50, 0x11D50, 0x11DA0, 0x11F50, 0x16A60,
+    0x16AC0, 0x16B50, 0x1D7CE, 0x1D7D8, 0x1D7E2, 0x1D7EC, 0x1D7F6, 0x1E140, 0x1E2F0, 0x1E4F0,
+    0x1E950, 0x1FBF0,
+];
+
+fn expect_char(name: &str, obj: &Object) -> Result<char, String> {
+    match obj {
+        Object::Char(c) => Ok(*c),
+        _ => Err(format!("{} expects a char, but was given {}", name, obj)),
+    }
+}
+
+/// The value of a decimal digit, in any script
+fn to_digit(c: char) -> Option<u32> {
+    let code = c as u32;
+
+    DIGIT_ZEROS
+        .iter()
+        .find(|zero| (**zero..**zero + 10).contains(&code))
+        .map(|zero| code - zero)
+}
+
+/// Applies a case mapping, but only if it maps the char to a single char
+fn map_case<I: Iterator<Item = char>>(c: char, mut mapped: I) -> char {
+    match (mapped.next(), mapped.next()) {
+        (Some(m), None) => m,
+        _ => c,
+    }
+}
+
+fn to_upper(c: char) -> char {
+    map_case(c, c.to_uppercase())
+}
+
+fn to_lower(c: char) -> char {
+    map_case(c, c.to_lowercase())
+}
+
+fn chain(
+    name: &str,
+    args: &[Object],
+    fold: bool,
+    op: fn(&char, &char) -> bool,
+) -> Result<Object, String> {
+    let chars = args
+        .iter()
+        .map(|arg| expect_char(name, arg).map(|c| if fold { to_lower(c) } else { c }))
+        .collect::<Result<Vec<char>, String>>()?;
+
+    Ok(Object::Bool(
+        chars.windows(2).all(|pair| op(&pair[0], &pair[1])),
+    ))
+}
+
+fn is_char(args: &[Object]) -> Result<Object, String> {
+    Ok(Object::Bool(matches!(args[0], Object::Char(_))))
+}
+
+fn char_to_integer(args: &[Object]) -> Result<Object, String> {
+    Ok(Object::Integer(
+        expect_char("char->integer", &args[0])? as i64
+    ))
+}
+
+fn integer_to_char(args: &[Object]) -> Result<Object, String> {
+    let c = match &args[0] {
+        Object::Integer(n) => u32::try_from(*n).ok().and_then(char::from_u32),
+        _ => None,
+    };
+
+    c.map(Object::Char).ok_or(format!(
+        "integer->char expects a Unicode scalar value, but was given {}",
+        args[0]
+    ))
+}
+
+fn eq(args: &[Object]) -> Result<Object, String> {
+    chain("char=?", args, false, char::eq)
+}
+
+fn lt(args: &[Object]) -> Result<Object, String> {
+    chain("char<?", args, false, char::lt)
+}
+
+fn gt(args: &[Object]) -> Result<Object, String> {
+    chain("char>?", args, false, char::gt)
+}
+
+fn le(args: &[Object]) -> Result<Object, String> {
+    chain("char<=?", args, false, char::le)
+}
+
+fn ge(args: &[Object]) -> Result<Object, String> {
+    chain("char>=?", args, false, char::ge)
+}
+
+fn eq_ci(args: &[Object]) -> Result<Object, String> {
+    chain("char-ci=?", args, true, char::eq)
+}
+
+fn lt_ci(args: &[Object]) -> Result<Object, String> {
+    chain("char-ci<?", args, true, char::lt)
+}
+
+fn gt_ci(args: &[Object]) -> Result<Object, String> {
+    chain("char-ci>?", args, true, char::gt)
+}
+
+fn le_ci(args: &[Object]) -> Result<Object, String> {
+    chain("char-ci<=?", args, true, char::le)
+}
+
+fn ge_ci(args: &[Object]) -> Result<Object, String> {
+    chain("char-ci>=?", args, true, char::ge)
+}
+
+fn upcase(args: &[Object]) -> Result<Object, String> {
+    Ok(Object::Char(to_upper(expect_char(
+        "char-upcase",
+        &args[0],
+    )?)))
+}
+
+fn downcase(args: &[Object]) -> Result<Object, String> {
+    Ok(Object::Char(to_lower(expect_char(
+        "char-downcase",
+        &args[0],
+    )?)))
+}
+
+fn foldcase(args: &[Object]) -> Result<Object, String> {
+    Ok(Object::Char(to_lower(expect_char(
+        "char-foldcase",
+        &args[0],
+    )?)))
+}
+
+fn is_alphabetic(args: &[Object]) -> Result<Object, String> {
+    Ok(Object::Bool(
+        expect_char("char-alphabetic?", &args[0])?.is_alphabetic(),
+    ))
+}
+
+fn is_numeric(args: &[Object]) -> Result<Object, String> {
+    Ok(Object::Bool(
+        to_digit(expect_char("char-numeric?", &args[0])?).is_some(),
+    ))
+}
+
+fn is_whitespace(args: &[Object]) -> Result<Object, String> {
+    Ok(Object::Bool(
+        expect_char("char-whitespace?", &args[0])?.is_whitespace(),
+    ))
+}
+
+fn is_upper_case(args: &[Object]) -> Result<Object, String> {
+    Ok(Object::Bool(
+        expect_char("char-upper-case?", &args[0])?.is_uppercase(),
+    ))
+}
+
+fn is_lower_case(args: &[Object]) -> Result<Object, String> {
+    Ok(Object::Bool(
+        expect_char("char-lower-case?", &args[0])?.is_lowercase(),
+    ))
+}
+
+fn digit_value(args: &[Object]) -> Result<Object, String> {
+    Ok(match to_digit(expect_char("digit-value", &args[0])?) {
+        Some(d) => Object::Integer(d as i64),
+        None => Object::Bool(false),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars(cs: &str) -> Vec<Object> {
+        cs.chars().map(Object::Char).collect()
+    }
+
+    #[test]
+    fn digit_zeros_are_numeric() {
+        for zero in DIGIT_ZEROS {
+            for code in *zero..*zero + 10 {
+                assert!(char::from_u32(code).unwrap().is_numeric(), "{:x}", code);
+            }
+        }
+    }
+
+    #[test]
+    fn digit_values() {
+        assert_eq!(digit_value(&chars("3")), Ok(Object::Integer(3)));
+        assert_eq!(digit_value(&chars("٤")), Ok(Object::Integer(4)));
+        assert_eq!(digit_value(&chars("\u{0EA6}")), Ok(Object::Bool(false)));
+        assert_eq!(digit_value(&chars("a")), Ok(Object::Bool(false)));
+        // a numeric char that is not a decimal digit
+        assert_eq!(digit_value(&chars("½")), Ok(Object::Bool(false)));
+        assert_eq!(is_numeric(&chars("½")), Ok(Object::Bool(false)));
+    }
+
+    #[test]
+    fn case_mappings() {
+        assert_eq!(upcase(&chars("a")), Ok(Object::Char('A')));
+        assert_eq!(upcase(&chars("λ")), Ok(Object::Char('Λ')));
+        assert_eq!(downcase(&chars("Λ")), Ok(Object::Char('λ')));
+        // ß has no single char upper case
+        assert_eq!(upcase(&chars("ß")), Ok(Object::Char('ß')));
+        assert_eq!(foldcase(&chars("A")), Ok(Object::Char('a')));
+    }
+
+    #[test]
+    fn chained_comparisons() {
+        assert_eq!(lt(&chars("abc")), Ok(Object::Bool(true)));
+        assert_eq!(lt(&chars("acb")), Ok(Object::Bool(false)));
+        assert_eq!(eq(&chars("aA")), Ok(Object::Bool(false)));
+        assert_eq!(eq_ci(&chars("aA")), Ok(Object::Bool(true)));
+        assert_eq!(lt_ci(&chars("aB")), Ok(Object::Bool(true)));
+        assert!(lt(&[Object::Char('a'), Object::Integer(1)]).is_err());
+    }
+
+    #[test]
+    fn integer_conversions() {
+        assert_eq!(char_to_integer(&chars("λ")), Ok(Object::Integer(0x3bb)));
+        assert_eq!(
+            integer_to_char(&[Object::Integer(0x3bb)]),
+            Ok(Object::Char('λ'))
+        );
+        assert!(integer_to_char(&[Object::Integer(0xd800)]).is_err());
+        assert!(integer_to_char(&[Object::Integer(-1)]).is_err());
+    }
+}
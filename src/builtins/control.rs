@@ -0,0 +1,90 @@
+use crate::eval::{apply, apply_single};
+use crate::object::{Arity, Native, Object};
+
+pub static NATIVES: &[Native] = &[
+    Native {
+        name: "procedure?",
+        arity: Arity::Exactly(1),
+        func: is_procedure,
+    },
+    Native {
+        name: "apply",
+        arity: Arity::AtLeast(2),
+        func: apply_native,
+    },
+    Native {
+        name: "map",
+        arity: Arity::AtLeast(2),
+        func: map,
+    },
+    Native {
+        name: "for-each",
+        arity: Arity::AtLeast(2),
+        func: for_each,
+    },
+    Native {
+        name: "values",
+        arity: Arity::AtLeast(0),
+        func: values,
+    },
+    Native {
+        name: "call-with-values",
+        arity: Arity::Exactly(2),
+        func: call_with_values,
+    },
+];
+
+fn is_procedure(args: &[Object]) -> Result<Object, String> {
+    Ok(Object::Bool(matches!(
+        args[0],
+        Object::Lambda(_) | Object::CaseLambda(_) | Object::Native(_)
+    )))
+}
+
+fn apply_native(args: &[Object]) -> Result<Object, String> {
+    // (apply f a b '(c d)) calls (f a b c d)
+    let (last, firsts) = args[1..].split_last().unwrap();
+    let mut call_args = firsts.to_vec();
+
+    match last.list_to_vec() {
+        Some(rest) => call_args.extend(rest),
+        None => return Err(format!("apply expects a list, but was given {}", last)),
+    }
+
+    apply(&args[0], call_args)
+}
+
+/// Calls `function` on the elements of `lists` taken in parallel, until the shortest one is exhausted
+fn map_lists(name: &str, function: &Object, lists: &[Object]) -> Result<Vec<Object>, String> {
+    let lists = lists
+        .iter()
+        .map(|l| {
+            l.list_to_vec()
+                .ok_or_else(|| format!("{} expects a list, but was given {}", name, l))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let len = lists.iter().map(Vec::len).min().unwrap_or(0);
+
+    (0..len)
+        .map(|i| apply_single(function, lists.iter().map(|l| l[i].clone()).collect()))
+        .collect()
+}
+
+fn map(args: &[Object]) -> Result<Object, String> {
+    Ok(Object::list(map_lists("map", &args[0], &args[1..])?))
+}
+
+fn for_each(args: &[Object]) -> Result<Object, String> {
+    map_lists("for-each", &args[0], &args[1..])?;
+    Ok(Object::Unspecified)
+}
+
+fn values(args: &[Object]) -> Result<Object, String> {
+    Ok(Object::values(args.to_vec()))
+}
+
+/// Calls the consumer with the values returned by the producer, called without arguments
+fn call_with_values(args: &[Object]) -> Result<Object, String> {
+    let values = apply(&args[0], Vec::new())?;
+    apply(&args[1], values.values_to_vec())
+}
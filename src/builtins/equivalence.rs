@@ -0,0 +1,121 @@
+use std::rc::Rc;
+
+use crate::object::{Arity, Native, Object};
+
+pub static NATIVES: &[Native] = &[
+    Native {
+        name: "eq?",
+        arity: Arity::Exactly(2),
+        func: eq,
+    },
+    Native {
+        name: "eqv?",
+        arity: Arity::Exactly(2),
+        func: eqv,
+    },
+    Native {
+        name: "equal?",
+        arity: Arity::Exactly(2),
+        func: equal,
+    },
+];
+
+/// Whether two objects are the same: objects stored on the heap are compared by identity,
+/// and numbers must have the same exactness to be equivalent
+fn is_eqv(a: &Object, b: &Object) -> bool {
+    match (a, b) {
+        (Object::String(a), Object::String(b)) => Rc::ptr_eq(a, b),
+        (Object::Pair(a), Object::Pair(b)) => Rc::ptr_eq(a, b),
+        (Object::Vector(a), Object::Vector(b)) => Rc::ptr_eq(a, b),
+        (Object::Bytevector(a), Object::Bytevector(b)) => Rc::ptr_eq(a, b),
+        (Object::Lambda(a), Object::Lambda(b)) => Rc::ptr_eq(a, b),
+        (Object::CaseLambda(a), Object::CaseLambda(b)) => Rc::ptr_eq(a, b),
+        // unlike ==, -0.0 is not 0.0 and NaN is NaN
+        (Object::Float(a), Object::Float(b)) => a.to_bits() == b.to_bits(),
+        (Object::Complex(a), Object::Complex(b)) => {
+            a.re.to_bits() == b.re.to_bits() && a.im.to_bits() == b.im.to_bits()
+        }
+        _ => std::mem::discriminant(a) == std::mem::discriminant(b) && a == b,
+    }
+}
+
+/// Whether two objects have the same content: pairs, strings, vectors and bytevectors are compared
+/// element by element, and every other object with eqv?
+fn is_equal(a: &Object, b: &Object) -> bool {
+    let (mut a, mut b) = (a.clone(), b.clone());
+
+    // the cdrs of lists are followed in a loop, so that long lists do not use the stack
+    while let (Object::Pair(pa), Object::Pair(pb)) = (&a, &b) {
+        if !is_equal(&pa.car(), &pb.car()) {
+            return false;
+        }
+        (a, b) = (pa.cdr(), pb.cdr());
+    }
+
+    match (&a, &b) {
+        (Object::String(a), Object::String(b)) => *a.chars.borrow() == *b.chars.borrow(),
+        (Object::Vector(a), Object::Vector(b)) => {
+            let (a, b) = (a.items.borrow(), b.items.borrow());
+            a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| is_equal(a, b))
+        }
+        (Object::Bytevector(a), Object::Bytevector(b)) => *a.bytes.borrow() == *b.bytes.borrow(),
+        _ => is_eqv(&a, &b),
+    }
+}
+
+fn eq(args: &[Object]) -> Result<Object, String> {
+    // small numbers and chars are not boxed, so eq? cannot be more precise than eqv?
+    Ok(Object::Bool(is_eqv(&args[0], &args[1])))
+}
+
+fn eqv(args: &[Object]) -> Result<Object, String> {
+    Ok(Object::Bool(is_eqv(&args[0], &args[1])))
+}
+
+fn equal(args: &[Object]) -> Result<Object, String> {
+    Ok(Object::Bool(is_equal(&args[0], &args[1])))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strings_are_compared_by_identity() {
+        let a = Object::string("abc");
+
+        assert!(is_eqv(&a, &a.clone()));
+        assert!(!is_eqv(&a, &Object::string("abc")));
+        assert_eq!(equal(&[a, Object::string("abc")]), Ok(Object::Bool(true)));
+    }
+
+    #[test]
+    fn numbers_keep_their_exactness() {
+        assert!(is_eqv(&Object::Integer(2), &Object::Integer(2)));
+        assert!(!is_eqv(&Object::Integer(2), &Object::Float(2.0)));
+        assert!(is_eqv(&Object::Float(2.0), &Object::Float(2.0)));
+        assert!(!is_eqv(&Object::Float(0.0), &Object::Float(-0.0)));
+        assert!(is_eqv(&Object::Float(f64::NAN), &Object::Float(f64::NAN)));
+    }
+
+    #[test]
+    fn equal_agrees_with_eqv_on_atoms() {
+        let nan = Object::Float(f64::NAN);
+
+        assert!(is_equal(&nan, &nan));
+        assert!(!is_equal(&Object::Float(0.0), &Object::Float(-0.0)));
+        assert!(!is_equal(&Object::Integer(2), &Object::Float(2.0)));
+        assert!(is_equal(
+            &Object::list(vec![Object::vector(vec![nan.clone()]), Object::string("a")]),
+            &Object::list(vec![Object::vector(vec![nan]), Object::string("a")])
+        ));
+        assert!(!is_equal(
+            &Object::list(vec![Object::Float(0.0)]),
+            &Object::list(vec![Object::Float(-0.0)])
+        ));
+        assert!(is_equal(
+            &Object::bytevector(vec![1, 2]),
+            &Object::bytevector(vec![1, 2])
+        ));
+    }
+}
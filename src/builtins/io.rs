@@ -0,0 +1,44 @@
+use std::io::Write;
+
+use crate::object::{Arity, Native, Object};
+
+pub static NATIVES: &[Native] = &[
+    Native {
+        name: "display",
+        arity: Arity::Exactly(1),
+        func: display,
+    },
+    Native {
+        name: "write",
+        arity: Arity::Exactly(1),
+        func: write,
+    },
+    Native {
+        name: "newline",
+        arity: Arity::Exactly(0),
+        func: newline,
+    },
+];
+
+fn print(args: std::fmt::Arguments) -> Result<Object, String> {
+    let mut stdout = std::io::stdout();
+
+    stdout
+        .write_fmt(args)
+        .and_then(|_| stdout.flush())
+        .map_err(|e| e.to_string())?;
+
+    Ok(Object::Unspecified)
+}
+
+fn display(args: &[Object]) -> Result<Object, String> {
+    print(format_args!("{}", args[0].display()))
+}
+
+fn write(args: &[Object]) -> Result<Object, String> {
+    print(format_args!("{}", args[0]))
+}
+
+fn newline(_args: &[Object]) -> Result<Object, String> {
+    print(format_args!("\n"))
+}
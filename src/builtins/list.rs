@@ -0,0 +1,87 @@
+use std::rc::Rc;
+
+use crate::object::{Arity, Native, Object, Pair};
+
+pub static NATIVES: &[Native] = &[
+    Native {
+        name: "cons",
+        arity: Arity::Exactly(2),
+        func: cons,
+    },
+    Native {
+        name: "car",
+        arity: Arity::Exactly(1),
+        func: car,
+    },
+    Native {
+        name: "cdr",
+        arity: Arity::Exactly(1),
+        func: cdr,
+    },
+    Native {
+        name: "set-car!",
+        arity: Arity::Exactly(2),
+        func: set_car,
+    },
+    Native {
+        name: "set-cdr!",
+        arity: Arity::Exactly(2),
+        func: set_cdr,
+    },
+    Native {
+        name: "list",
+        arity: Arity::AtLeast(0),
+        func: list,
+    },
+    Native {
+        name: "pair?",
+        arity: Arity::Exactly(1),
+        func: is_pair,
+    },
+    Native {
+        name: "null?",
+        arity: Arity::Exactly(1),
+        func: is_null,
+    },
+];
+
+fn expect_pair<'a>(name: &str, obj: &'a Object) -> Result<&'a Rc<Pair>, String> {
+    match obj {
+        Object::Pair(pair) => Ok(pair),
+        _ => Err(format!("{} expects a pair, but was given {}", name, obj)),
+    }
+}
+
+fn cons(args: &[Object]) -> Result<Object, String> {
+    Ok(Object::cons(args[0].clone(), args[1].clone()))
+}
+
+fn car(args: &[Object]) -> Result<Object, String> {
+    Ok(expect_pair("car", &args[0])?.car())
+}
+
+fn cdr(args: &[Object]) -> Result<Object, String> {
+    Ok(expect_pair("cdr", &args[0])?.cdr())
+}
+
+fn set_car(args: &[Object]) -> Result<Object, String> {
+    *expect_pair("set-car!", &args[0])?.car.borrow_mut() = args[1].clone();
+    Ok(Object::Unspecified)
+}
+
+fn set_cdr(args: &[Object]) -> Result<Object, String> {
+    *expect_pair("set-cdr!", &args[0])?.cdr.borrow_mut() = args[1].clone();
+    Ok(Object::Unspecified)
+}
+
+fn list(args: &[Object]) -> Result<Object, String> {
+    Ok(Object::list(args.to_vec()))
+}
+
+fn is_pair(args: &[Object]) -> Result<Object, String> {
+    Ok(Object::Bool(matches!(args[0], Object::Pair(_))))
+}
+
+fn is_null(args: &[Object]) -> Result<Object, String> {
+    Ok(Object::Bool(args[0] == Object::Nil))
+}
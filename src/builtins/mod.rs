@@ -0,0 +1,84 @@
+mod bytevector;
+mod char;
+mod control;
+mod equivalence;
+mod io;
+mod list;
+mod numeric;
+mod string;
+mod symbol;
+mod system;
+mod vector;
+
+use std::ops::Range;
+
+use crate::object::{Native, Object};
+
+pub use system::set_command_line;
+
+/// Every procedure of the global environment
+pub fn natives() -> impl Iterator<Item = &'static Native> {
+    bytevector::NATIVES
+        .iter()
+        .chain(char::NATIVES)
+        .chain(control::NATIVES)
+        .chain(equivalence::NATIVES)
+        .chain(io::NATIVES)
+        .chain(list::NATIVES)
+        .chain(numeric::NATIVES)
+        .chain(string::NATIVES)
+        .chain(symbol::NATIVES)
+        .chain(system::NATIVES)
+        .chain(vector::NATIVES)
+}
+
+/// Checks that `obj` is an index in `[0, bound)`
+fn expect_index(name: &str, obj: &Object, bound: usize) -> Result<usize, String> {
+    match obj {
+        Object::Integer(i) if (0..bound as i64).contains(i) => Ok(*i as usize),
+        Object::Integer(i) => Err(format!(
+            "{}: index {} is out of range [0, {})",
+            name, i, bound
+        )),
+        _ => Err(format!("{} expects an index, but was given {}", name, obj)),
+    }
+}
+
+/// Checks that `count` elements fit at index `at` of a sequence of length `len`,
+/// returning the range they are copied to
+fn expect_target(
+    name: &str,
+    at: &Object,
+    count: usize,
+    len: usize,
+) -> Result<Range<usize>, String> {
+    let at = expect_index(name, at, len + 1)?;
+
+    if count > len - at {
+        return Err(format!(
+            "{}: cannot copy {} elements at index {} of a sequence of length {}",
+            name, count, at, len
+        ));
+    }
+
+    Ok(at..at + count)
+}
+
+/// Reads the optional `start` and `end` arguments delimiting a part of a sequence of length `len`
+fn expect_range(
+    name: &str,
+    start: Option<&Object>,
+    end: Option<&Object>,
+    len: usize,
+) -> Result<(usize, usize), String> {
+    let end = match end {
+        Some(end) => expect_index(name, end, len + 1)?,
+        None => len,
+    };
+    let start = match start {
+        Some(start) => expect_index(name, start, end + 1)?,
+        None => 0,
+    };
+
+    Ok((start, end))
+}
@@ -0,0 +1,502 @@
+use std::cmp::Ordering;
+
+use crate::number;
+use crate::object::{Arity, Native, Object};
+
+pub static NATIVES: &[Native] = &[
+    Native {
+        name: "+",
+        arity: Arity::AtLeast(0),
+        func: add,
+    },
+    Native {
+        name: "-",
+        arity: Arity::AtLeast(1),
+        func: sub,
+    },
+    Native {
+        name: "*",
+        arity: Arity::AtLeast(0),
+        func: mul,
+    },
+    Native {
+        name: "/",
+        arity: Arity::AtLeast(1),
+        func: div,
+    },
+    Native {
+        name: "=",
+        arity: Arity::AtLeast(2),
+        func: eq,
+    },
+    Native {
+        name: "<",
+        arity: Arity::AtLeast(2),
+        func: lt,
+    },
+    Native {
+        name: ">",
+        arity: Arity::AtLeast(2),
+        func: gt,
+    },
+    Native {
+        name: "<=",
+        arity: Arity::AtLeast(2),
+        func: le,
+    },
+    Native {
+        name: ">=",
+        arity: Arity::AtLeast(2),
+        func: ge,
+    },
+    Native {
+        name: "!=",
+        arity: Arity::Exactly(2),
+        func: ne,
+    },
+    Native {
+        name: "number?",
+        arity: Arity::Exactly(1),
+        func: is_number,
+    },
+    Native {
+        name: "complex?",
+        arity: Arity::Exactly(1),
+        func: is_number,
+    },
+    Native {
+        name: "real?",
+        arity: Arity::Exactly(1),
+        func: is_real,
+    },
+    Native {
+        name: "rational?",
+        arity: Arity::Exactly(1),
+        func: is_rational,
+    },
+    Native {
+        name: "integer?",
+        arity: Arity::Exactly(1),
+        func: is_integer,
+    },
+    Native {
+        name: "exact?",
+        arity: Arity::Exactly(1),
+        func: is_exact,
+    },
+    Native {
+        name: "inexact?",
+        arity: Arity::Exactly(1),
+        func: is_inexact,
+    },
+    Native {
+        name: "exact",
+        arity: Arity::Exactly(1),
+        func: exact,
+    },
+    Native {
+        name: "inexact",
+        arity: Arity::Exactly(1),
+        func: inexact,
+    },
+    Native {
+        name: "inexact->exact",
+        arity: Arity::Exactly(1),
+        func: exact,
+    },
+    Native {
+        name: "exact->inexact",
+        arity: Arity::Exactly(1),
+        func: inexact,
+    },
+    Native {
+        name: "numerator",
+        arity: Arity::Exactly(1),
+        func: numerator,
+    },
+    Native {
+        name: "denominator",
+        arity: Arity::Exactly(1),
+        func: denominator,
+    },
+    Native {
+        name: "sqrt",
+        arity: Arity::Exactly(1),
+        func: sqrt,
+    },
+    Native {
+        name: "make-rectangular",
+        arity: Arity::Exactly(2),
+        func: make_rectangular,
+    },
+    Native {
+        name: "make-polar",
+        arity: Arity::Exactly(2),
+        func: make_polar,
+    },
+    Native {
+        name: "real-part",
+        arity: Arity::Exactly(1),
+        func: real_part,
+    },
+    Native {
+        name: "imag-part",
+        arity: Arity::Exactly(1),
+        func: imag_part,
+    },
+    Native {
+        name: "magnitude",
+        arity: Arity::Exactly(1),
+        func: magnitude,
+    },
+    Native {
+        name: "angle",
+        arity: Arity::Exactly(1),
+        func: angle,
+    },
+    Native {
+        name: "number->string",
+        arity: Arity::Between(1, 2),
+        func: number_to_string,
+    },
+    Native {
+        name: "string->number",
+        arity: Arity::Between(1, 2),
+        func: string_to_number,
+    },
+];
+
+/// Folds the arguments from left to right, starting with `init`
+fn fold(operator: &str, init: Object, args: &[Object]) -> Result<Object, String> {
+    args.iter()
+        .try_fold(init, |acc, arg| binop(operator, &acc, arg))
+}
+
+/// `(- x)` and `(/ x)` apply the unary inverse, anything else is a left fold
+fn fold_inverse(
+    operator: &str,
+    inverse: fn(&Object) -> Result<Object, String>,
+    args: &[Object],
+) -> Result<Object, String> {
+    match args {
+        [arg] => inverse(arg),
+        [first, rest @ ..] => fold(operator, first.clone(), rest),
+        [] => unreachable!("Arity is checked before the call"),
+    }
+}
+
+/// Checks that the comparison holds between each consecutive arguments
+fn chain(operator: &str, args: &[Object]) -> Result<Object, String> {
+    let mut result = true;
+
+    // every argument is compared, so that a type error is reported even if the result is known
+    for pair in args.windows(2) {
+        if binop(operator, &pair[0], &pair[1])? == Object::Bool(false) {
+            result = false;
+        }
+    }
+
+    Ok(Object::Bool(result))
+}
+
+fn add(args: &[Object]) -> Result<Object, String> {
+    fold("+", Object::Integer(0), args)
+}
+
+fn sub(args: &[Object]) -> Result<Object, String> {
+    fold_inverse("-", number::neg, args)
+}
+
+fn mul(args: &[Object]) -> Result<Object, String> {
+    fold("*", Object::Integer(1), args)
+}
+
+fn div(args: &[Object]) -> Result<Object, String> {
+    fold_inverse("/", |x| number::div(&Object::Integer(1), x), args)
+}
+
+fn eq(args: &[Object]) -> Result<Object, String> {
+    chain("=", args)
+}
+
+fn lt(args: &[Object]) -> Result<Object, String> {
+    chain("<", args)
+}
+
+fn gt(args: &[Object]) -> Result<Object, String> {
+    chain(">", args)
+}
+
+fn le(args: &[Object]) -> Result<Object, String> {
+    chain("<=", args)
+}
+
+fn ge(args: &[Object]) -> Result<Object, String> {
+    chain(">=", args)
+}
+
+fn ne(args: &[Object]) -> Result<Object, String> {
+    binop("!=", &args[0], &args[1])
+}
+
+fn binop(operator: &str, left: &Object, right: &Object) -> Result<Object, String> {
+    match operator {
+        "+" => number::add(left, right),
+        "-" => number::sub(left, right),
+        "*" => number::mul(left, right),
+        "/" => number::div(left, right),
+        _ => {
+            let ordering = number::compare(operator, left, right)?;
+
+            let result = match operator {
+                "<" => ordering == Some(Ordering::Less),
+                ">" => ordering == Some(Ordering::Greater),
+                "<=" => matches!(ordering, Some(Ordering::Less | Ordering::Equal)),
+                ">=" => matches!(ordering, Some(Ordering::Greater | Ordering::Equal)),
+                "=" => ordering == Some(Ordering::Equal),
+                "!=" => ordering != Some(Ordering::Equal), // non-standard
+                _ => unreachable!("Unknown binary operator !"),
+            };
+
+            Ok(Object::Bool(result))
+        }
+    }
+}
+
+fn expect_number<'a>(name: &str, obj: &'a Object) -> Result<&'a Object, String> {
+    if number::is_number(obj) {
+        Ok(obj)
+    } else {
+        Err(format!("{} expects a number, but was given {}", name, obj))
+    }
+}
+
+fn is_number(args: &[Object]) -> Result<Object, String> {
+    Ok(Object::Bool(number::is_number(&args[0])))
+}
+
+fn is_real(args: &[Object]) -> Result<Object, String> {
+    Ok(Object::Bool(number::is_real(&args[0])))
+}
+
+fn is_integer(args: &[Object]) -> Result<Object, String> {
+    Ok(Object::Bool(number::is_integer(&args[0])))
+}
+
+fn is_rational(args: &[Object]) -> Result<Object, String> {
+    let rational = match &args[0] {
+        Object::Float(f) => f.is_finite(),
+        obj => number::is_real(obj),
+    };
+
+    Ok(Object::Bool(rational))
+}
+
+fn is_exact(args: &[Object]) -> Result<Object, String> {
+    match number::is_exact(&args[0]) {
+        Some(exact) => Ok(Object::Bool(exact)),
+        None => Err(format!(
+            "exact? expects a number, but was given {}",
+            args[0]
+        )),
+    }
+}
+
+fn is_inexact(args: &[Object]) -> Result<Object, String> {
+    match number::is_exact(&args[0]) {
+        Some(exact) => Ok(Object::Bool(!exact)),
+        None => Err(format!(
+            "inexact? expects a number, but was given {}",
+            args[0]
+        )),
+    }
+}
+
+fn exact(args: &[Object]) -> Result<Object, String> {
+    number::to_exact(expect_number("exact", &args[0])?)
+}
+
+fn inexact(args: &[Object]) -> Result<Object, String> {
+    number::to_inexact(expect_number("inexact", &args[0])?)
+}
+
+fn numerator(args: &[Object]) -> Result<Object, String> {
+    number::numerator(expect_number("numerator", &args[0])?)
+}
+
+fn denominator(args: &[Object]) -> Result<Object, String> {
+    number::denominator(expect_number("denominator", &args[0])?)
+}
+
+fn sqrt(args: &[Object]) -> Result<Object, String> {
+    number::sqrt(expect_number("sqrt", &args[0])?)
+}
+
+fn make_rectangular(args: &[Object]) -> Result<Object, String> {
+    number::make_rectangular(&args[0], &args[1])
+}
+
+fn make_polar(args: &[Object]) -> Result<Object, String> {
+    number::make_polar(&args[0], &args[1])
+}
+
+fn real_part(args: &[Object]) -> Result<Object, String> {
+    number::real_part(expect_number("real-part", &args[0])?)
+}
+
+fn imag_part(args: &[Object]) -> Result<Object, String> {
+    number::imag_part(expect_number("imag-part", &args[0])?)
+}
+
+fn magnitude(args: &[Object]) -> Result<Object, String> {
+    number::magnitude(expect_number("magnitude", &args[0])?)
+}
+
+fn angle(args: &[Object]) -> Result<Object, String> {
+    number::angle(expect_number("angle", &args[0])?)
+}
+
+/// The optional radix argument of the conversion procedures, 10 by default
+fn radix(name: &str, args: &[Object]) -> Result<u32, String> {
+    match args.get(1) {
+        None => Ok(10),
+        Some(Object::Integer(radix @ (2 | 8 | 10 | 16))) => Ok(*radix as u32),
+        Some(radix) => Err(format!(
+            "{} expects a radix of 2, 8, 10 or 16, but was given {}",
+            name, radix
+        )),
+    }
+}
+
+fn number_to_string(args: &[Object]) -> Result<Object, String> {
+    let radix = radix("number->string", args)?;
+    number::to_string(expect_number("number->string", &args[0])?, radix).map(Object::string)
+}
+
+fn string_to_number(args: &[Object]) -> Result<Object, String> {
+    let radix = radix("string->number", args)?;
+
+    match &args[0] {
+        Object::String(s) => {
+            Ok(number::parse_number(&s.chars.borrow(), radix).unwrap_or(Object::Bool(false)))
+        }
+        obj => Err(format!(
+            "string->number expects a string, but was given {}",
+            obj
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ints(values: &[i64]) -> Vec<Object> {
+        values.iter().map(|n| Object::Integer(*n)).collect()
+    }
+
+    #[test]
+    fn variadic_arithmetic() {
+        assert_eq!(add(&ints(&[])), Ok(Object::Integer(0)));
+        assert_eq!(add(&ints(&[1, 2, 3])), Ok(Object::Integer(6)));
+        assert_eq!(mul(&ints(&[])), Ok(Object::Integer(1)));
+        assert_eq!(mul(&ints(&[2, 3, 4])), Ok(Object::Integer(24)));
+        assert_eq!(sub(&ints(&[10, 1, 2])), Ok(Object::Integer(7)));
+        assert_eq!(div(&ints(&[100, 5, 2])), Ok(Object::Integer(10)));
+    }
+
+    #[test]
+    fn unary_negation_and_reciprocal() {
+        assert_eq!(sub(&ints(&[5])), Ok(Object::Integer(-5)));
+        assert_eq!(sub(&[Object::Float(1.5)]), Ok(Object::Float(-1.5)));
+        assert_eq!(div(&[Object::Float(4.0)]), Ok(Object::Float(0.25)));
+
+        match sub(&[Object::Float(0.0)]) {
+            Ok(Object::Float(f)) => assert!(f == 0.0 && f.is_sign_negative()),
+            other => panic!("Expected -0.0, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn promotion_to_float() {
+        assert_eq!(
+            add(&[Object::Integer(1), Object::Float(0.5), Object::Integer(1)]),
+            Ok(Object::Float(2.5))
+        );
+    }
+
+    #[test]
+    fn integer_overflow_promotes_to_bignum() {
+        assert_eq!(
+            add(&ints(&[i64::MAX, 1])).unwrap().to_string(),
+            "9223372036854775808"
+        );
+        assert_eq!(
+            sub(&ints(&[i64::MIN, 1])).unwrap().to_string(),
+            "-9223372036854775809"
+        );
+        assert_eq!(
+            sub(&ints(&[i64::MIN])).unwrap().to_string(),
+            "9223372036854775808"
+        );
+        assert_eq!(
+            mul(&ints(&[i64::MAX, 2])).unwrap().to_string(),
+            "18446744073709551614"
+        );
+        assert_eq!(
+            div(&ints(&[i64::MIN, -1])).unwrap().to_string(),
+            "9223372036854775808"
+        );
+    }
+
+    #[test]
+    fn division_by_zero_errors() {
+        assert_eq!(
+            div(&ints(&[1, 0])),
+            Err("Division by zero: (/ 1 0)".to_string())
+        );
+        assert!(div(&ints(&[0])).is_err());
+        assert_eq!(
+            div(&[Object::Float(1.0), Object::Float(0.0)]),
+            Ok(Object::Float(f64::INFINITY))
+        );
+    }
+
+    #[test]
+    fn division_is_exact() {
+        assert_eq!(div(&ints(&[6, 3])), Ok(Object::Integer(2)));
+        assert_eq!(div(&ints(&[1, 2])).unwrap().to_string(), "1/2");
+        assert_eq!(div(&ints(&[-7, 2])).unwrap().to_string(), "-7/2");
+        assert_eq!(div(&ints(&[4])).unwrap().to_string(), "1/4");
+        assert_eq!(div(&ints(&[-4, 6])).unwrap().to_string(), "-2/3");
+    }
+
+    #[test]
+    fn exactness_predicates() {
+        let third = [div(&ints(&[1, 3])).unwrap()];
+
+        assert_eq!(is_exact(&third), Ok(Object::Bool(true)));
+        assert_eq!(is_inexact(&third), Ok(Object::Bool(false)));
+        assert_eq!(is_exact(&[Object::Float(0.5)]), Ok(Object::Bool(false)));
+        assert!(is_exact(&[Object::Nil]).is_err());
+    }
+
+    #[test]
+    fn chained_comparisons() {
+        assert_eq!(lt(&ints(&[1, 2, 3])), Ok(Object::Bool(true)));
+        assert_eq!(lt(&ints(&[1, 3, 2])), Ok(Object::Bool(false)));
+        assert_eq!(le(&ints(&[1, 1, 2])), Ok(Object::Bool(true)));
+        assert_eq!(gt(&ints(&[3, 2, 2])), Ok(Object::Bool(false)));
+        assert_eq!(ge(&ints(&[3, 2, 2])), Ok(Object::Bool(true)));
+        assert_eq!(eq(&ints(&[2, 2, 2])), Ok(Object::Bool(true)));
+        assert_eq!(
+            eq(&[Object::Integer(2), Object::Float(2.0)]),
+            Ok(Object::Bool(true))
+        );
+    }
+
+    #[test]
+    fn comparison_checks_every_argument() {
+        assert!(lt(&[Object::Integer(2), Object::Integer(1), Object::symbol("a")]).is_err());
+    }
+}
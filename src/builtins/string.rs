@@ -0,0 +1,442 @@
+use std::cell::Ref;
+
+use crate::eval::apply_single;
+use crate::object::{Arity, Native, Object, Str};
+
+use super::{expect_index, expect_range, expect_target};
+
+pub static NATIVES: &[Native] = &[
+    Native {
+        name: "string?",
+        arity: Arity::Exactly(1),
+        func: is_string,
+    },
+    Native {
+        name: "make-string",
+        arity: Arity::Between(1, 2),
+        func: make_string,
+    },
+    Native {
+        name: "string",
+        arity: Arity::AtLeast(0),
+        func: string,
+    },
+    Native {
+        name: "string-length",
+        arity: Arity::Exactly(1),
+        func: string_length,
+    },
+    Native {
+        name: "string-ref",
+        arity: Arity::Exactly(2),
+        func: string_ref,
+    },
+    Native {
+        name: "substring",
+        arity: Arity::Exactly(3),
+        func: substring,
+    },
+    Native {
+        name: "string-set!",
+        arity: Arity::Exactly(3),
+        func: string_set,
+    },
+    Native {
+        name: "string-fill!",
+        arity: Arity::Between(2, 4),
+        func: string_fill,
+    },
+    Native {
+        name: "string-append",
+        arity: Arity::AtLeast(0),
+        func: string_append,
+    },
+    Native {
+        name: "string-copy",
+        arity: Arity::Between(1, 3),
+        func: string_copy,
+    },
+    Native {
+        name: "string-copy!",
+        arity: Arity::Between(3, 5),
+        func: string_copy_to,
+    },
+    Native {
+        name: "string->list",
+        arity: Arity::Between(1, 3),
+        func: string_to_list,
+    },
+    Native {
+        name: "list->string",
+        arity: Arity::Exactly(1),
+        func: list_to_string,
+    },
+    Native {
+        name: "string-upcase",
+        arity: Arity::Exactly(1),
+        func: upcase,
+    },
+    Native {
+        name: "string-downcase",
+        arity: Arity::Exactly(1),
+        func: downcase,
+    },
+    Native {
+        name: "string-foldcase",
+        arity: Arity::Exactly(1),
+        func: foldcase,
+    },
+    Native {
+        name: "string-index",
+        arity: Arity::Exactly(2),
+        func: string_index,
+    },
+    Native {
+        name: "string=?",
+        arity: Arity::AtLeast(2),
+        func: eq,
+    },
+    Native {
+        name: "string<?",
+        arity: Arity::AtLeast(2),
+        func: lt,
+    },
+    Native {
+        name: "string>?",
+        arity: Arity::AtLeast(2),
+        func: gt,
+    },
+    Native {
+        name: "string<=?",
+        arity: Arity::AtLeast(2),
+        func: le,
+    },
+    Native {
+        name: "string>=?",
+        arity: Arity::AtLeast(2),
+        func: ge,
+    },
+    Native {
+        name: "string-ci=?",
+        arity: Arity::AtLeast(2),
+        func: eq_ci,
+    },
+    Native {
+        name: "string-ci<?",
+        arity: Arity::AtLeast(2),
+        func: lt_ci,
+    },
+    Native {
+        name: "string-ci>?",
+        arity: Arity::AtLeast(2),
+        func: gt_ci,
+    },
+    Native {
+        name: "string-ci<=?",
+        arity: Arity::AtLeast(2),
+        func: le_ci,
+    },
+    Native {
+        name: "string-ci>=?",
+        arity: Arity::AtLeast(2),
+        func: ge_ci,
+    },
+];
+
+fn expect_string<'a>(name: &str, obj: &'a Object) -> Result<Ref<'a, String>, String> {
+    match obj {
+        Object::String(s) => Ok(s.chars.borrow()),
+        _ => Err(format!("{} expects a string, but was given {}", name, obj)),
+    }
+}
+
+fn expect_mutable_string<'a>(name: &str, obj: &'a Object) -> Result<&'a Str, String> {
+    match obj {
+        Object::String(s) if s.mutable => Ok(s),
+        Object::String(_) => Err(format!("{} cannot mutate the constant {}", name, obj)),
+        _ => Err(format!("{} expects a string, but was given {}", name, obj)),
+    }
+}
+
+fn expect_char(name: &str, obj: &Object) -> Result<char, String> {
+    match obj {
+        Object::Char(c) => Ok(*c),
+        _ => Err(format!("{} expects a char, but was given {}", name, obj)),
+    }
+}
+
+/// The chars of the string in `args[0]`, delimited by the optional start and end in `args[1..]`
+fn expect_substring(name: &str, args: &[Object]) -> Result<Vec<char>, String> {
+    let chars: Vec<char> = expect_string(name, &args[0])?.chars().collect();
+    let (start, end) = expect_range(name, args.get(1), args.get(2), chars.len())?;
+
+    Ok(chars[start..end].to_vec())
+}
+
+fn chain(
+    name: &str,
+    args: &[Object],
+    fold: bool,
+    op: fn(&str, &str) -> bool,
+) -> Result<Object, String> {
+    let strings = args
+        .iter()
+        .map(|arg| expect_string(name, arg))
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let holds = if fold {
+        let folded: Vec<String> = strings.iter().map(|s| s.to_lowercase()).collect();
+        folded.windows(2).all(|pair| op(&pair[0], &pair[1]))
+    } else {
+        strings.windows(2).all(|pair| op(&pair[0], &pair[1]))
+    };
+
+    Ok(Object::Bool(holds))
+}
+
+fn is_string(args: &[Object]) -> Result<Object, String> {
+    Ok(Object::Bool(matches!(args[0], Object::String(_))))
+}
+
+fn make_string(args: &[Object]) -> Result<Object, String> {
+    let len = match &args[0] {
+        Object::Integer(len) if *len >= 0 => *len as usize,
+        other => {
+            return Err(format!(
+                "make-string expects a length, but was given {}",
+                other
+            ))
+        }
+    };
+    let fill = match args.get(1) {
+        Some(fill) => expect_char("make-string", fill)?,
+        None => ' ',
+    };
+
+    Ok(Object::string(
+        std::iter::repeat_n(fill, len).collect::<String>(),
+    ))
+}
+
+fn string(args: &[Object]) -> Result<Object, String> {
+    args.iter()
+        .map(|arg| expect_char("string", arg))
+        .collect::<Result<String, String>>()
+        .map(Object::string)
+}
+
+fn string_length(args: &[Object]) -> Result<Object, String> {
+    let s = expect_string("string-length", &args[0])?;
+    Ok(Object::Integer(s.chars().count() as i64))
+}
+
+fn string_ref(args: &[Object]) -> Result<Object, String> {
+    let s = expect_string("string-ref", &args[0])?;
+    let index = expect_index("string-ref", &args[1], s.chars().count())?;
+
+    Ok(Object::Char(s.chars().nth(index).unwrap()))
+}
+
+fn substring(args: &[Object]) -> Result<Object, String> {
+    let chars = expect_substring("substring", args)?;
+    Ok(Object::string(chars.into_iter().collect::<String>()))
+}
+
+fn string_set(args: &[Object]) -> Result<Object, String> {
+    let s = expect_mutable_string("string-set!", &args[0])?;
+    let c = expect_char("string-set!", &args[2])?;
+
+    let mut chars: Vec<char> = s.chars.borrow().chars().collect();
+    let index = expect_index("string-set!", &args[1], chars.len())?;
+    chars[index] = c;
+
+    *s.chars.borrow_mut() = chars.into_iter().collect();
+    Ok(Object::Unspecified)
+}
+
+fn string_fill(args: &[Object]) -> Result<Object, String> {
+    let s = expect_mutable_string("string-fill!", &args[0])?;
+    let c = expect_char("string-fill!", &args[1])?;
+
+    let mut chars: Vec<char> = s.chars.borrow().chars().collect();
+    let (start, end) = expect_range("string-fill!", args.get(2), args.get(3), chars.len())?;
+    chars[start..end].fill(c);
+
+    *s.chars.borrow_mut() = chars.into_iter().collect();
+    Ok(Object::Unspecified)
+}
+
+fn string_append(args: &[Object]) -> Result<Object, String> {
+    let mut result = String::new();
+
+    for arg in args {
+        result.push_str(&expect_string("string-append", arg)?);
+    }
+
+    Ok(Object::string(result))
+}
+
+fn string_copy(args: &[Object]) -> Result<Object, String> {
+    let chars = expect_substring("string-copy", args)?;
+    Ok(Object::string(chars.into_iter().collect::<String>()))
+}
+
+/// (string-copy! to at from [start [end]]) copies a part of `from` into `to`, starting at index `at`
+fn string_copy_to(args: &[Object]) -> Result<Object, String> {
+    let to = expect_mutable_string("string-copy!", &args[0])?;
+    let from = expect_substring("string-copy!", &args[2..])?;
+
+    let mut chars: Vec<char> = to.chars.borrow().chars().collect();
+    let target = expect_target("string-copy!", &args[1], from.len(), chars.len())?;
+    chars[target].copy_from_slice(&from);
+
+    *to.chars.borrow_mut() = chars.into_iter().collect();
+    Ok(Object::Unspecified)
+}
+
+fn string_to_list(args: &[Object]) -> Result<Object, String> {
+    let chars = expect_substring("string->list", args)?;
+    Ok(Object::list(chars.into_iter().map(Object::Char).collect()))
+}
+
+fn list_to_string(args: &[Object]) -> Result<Object, String> {
+    let items = args[0].list_to_vec().ok_or(format!(
+        "list->string expects a list, but was given {}",
+        args[0]
+    ))?;
+
+    string(&items)
+}
+
+fn upcase(args: &[Object]) -> Result<Object, String> {
+    let s = expect_string("string-upcase", &args[0])?;
+    Ok(Object::string(s.to_uppercase()))
+}
+
+fn downcase(args: &[Object]) -> Result<Object, String> {
+    let s = expect_string("string-downcase", &args[0])?;
+    Ok(Object::string(s.to_lowercase()))
+}
+
+fn foldcase(args: &[Object]) -> Result<Object, String> {
+    let s = expect_string("string-foldcase", &args[0])?;
+    Ok(Object::string(s.to_lowercase()))
+}
+
+/// Returns the index of the first char that is either `args[1]` or satisfies the predicate `args[1]`
+fn string_index(args: &[Object]) -> Result<Object, String> {
+    // the string is copied, as the predicate may mutate it
+    let s = expect_string("string-index", &args[0])?.clone();
+
+    for (i, c) in s.chars().enumerate() {
+        let found = match &args[1] {
+            Object::Char(expected) => c == *expected,
+            predicate => apply_single(predicate, vec![Object::Char(c)])? != Object::Bool(false),
+        };
+
+        if found {
+            return Ok(Object::Integer(i as i64));
+        }
+    }
+
+    Ok(Object::Bool(false))
+}
+
+fn eq(args: &[Object]) -> Result<Object, String> {
+    chain("string=?", args, false, str::eq)
+}
+
+fn lt(args: &[Object]) -> Result<Object, String> {
+    chain("string<?", args, false, str::lt)
+}
+
+fn gt(args: &[Object]) -> Result<Object, String> {
+    chain("string>?", args, false, str::gt)
+}
+
+fn le(args: &[Object]) -> Result<Object, String> {
+    chain("string<=?", args, false, str::le)
+}
+
+fn ge(args: &[Object]) -> Result<Object, String> {
+    chain("string>=?", args, false, str::ge)
+}
+
+fn eq_ci(args: &[Object]) -> Result<Object, String> {
+    chain("string-ci=?", args, true, str::eq)
+}
+
+fn lt_ci(args: &[Object]) -> Result<Object, String> {
+    chain("string-ci<?", args, true, str::lt)
+}
+
+fn gt_ci(args: &[Object]) -> Result<Object, String> {
+    chain("string-ci>?", args, true, str::gt)
+}
+
+fn le_ci(args: &[Object]) -> Result<Object, String> {
+    chain("string-ci<=?", args, true, str::le)
+}
+
+fn ge_ci(args: &[Object]) -> Result<Object, String> {
+    chain("string-ci>=?", args, true, str::ge)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(ss: &[&str]) -> Vec<Object> {
+        ss.iter().map(|s| Object::string(*s)).collect()
+    }
+
+    #[test]
+    fn indices_count_chars() {
+        let s = strings(&["aλb"]);
+
+        assert_eq!(string_length(&s), Ok(Object::Integer(3)));
+        assert_eq!(
+            string_ref(&[s[0].clone(), Object::Integer(2)]),
+            Ok(Object::Char('b'))
+        );
+        assert_eq!(
+            substring(&[s[0].clone(), Object::Integer(1), Object::Integer(3)]),
+            Ok(Object::string("λb".to_string()))
+        );
+    }
+
+    #[test]
+    fn indices_are_bounds_checked() {
+        let s = strings(&["abc"]);
+
+        assert_eq!(
+            string_ref(&[s[0].clone(), Object::Integer(3)]),
+            Err("string-ref: index 3 is out of range [0, 3)".to_string())
+        );
+        assert!(string_ref(&[Object::string(String::new()), Object::Integer(0)]).is_err());
+        assert!(substring(&[s[0].clone(), Object::Integer(2), Object::Integer(1)]).is_err());
+        assert!(substring(&[s[0].clone(), Object::Integer(0), Object::Integer(4)]).is_err());
+        assert!(string_copy(&[s[0].clone(), Object::Integer(-1)]).is_err());
+    }
+
+    #[test]
+    fn chained_comparisons() {
+        assert_eq!(lt(&strings(&["a", "ab", "b"])), Ok(Object::Bool(true)));
+        assert_eq!(eq(&strings(&["abc", "ABC"])), Ok(Object::Bool(false)));
+        assert_eq!(eq_ci(&strings(&["abc", "ABC"])), Ok(Object::Bool(true)));
+        assert_eq!(ge(&strings(&["b", "b", "a"])), Ok(Object::Bool(true)));
+        assert!(eq(&[Object::string("a".to_string()), Object::Char('a')]).is_err());
+    }
+
+    #[test]
+    fn case_conversions() {
+        assert_eq!(
+            upcase(&strings(&["straße"])),
+            Ok(Object::string("STRASSE".to_string()))
+        );
+        assert_eq!(
+            downcase(&strings(&["ΛΑΜΒΔΑ"])),
+            Ok(Object::string("λαμβδα".to_string()))
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use crate::object::{Arity, Native, Object};
+use crate::symbol::Symbol;
+
+pub static NATIVES: &[Native] = &[
+    Native {
+        name: "symbol?",
+        arity: Arity::Exactly(1),
+        func: is_symbol,
+    },
+    Native {
+        name: "symbol=?",
+        arity: Arity::AtLeast(2),
+        func: symbol_eq,
+    },
+    Native {
+        name: "symbol->string",
+        arity: Arity::Exactly(1),
+        func: symbol_to_string,
+    },
+    Native {
+        name: "string->symbol",
+        arity: Arity::Exactly(1),
+        func: string_to_symbol,
+    },
+    Native {
+        name: "gensym",
+        arity: Arity::Between(0, 1),
+        func: gensym,
+    },
+    Native {
+        name: "generate-uninterned-symbol",
+        arity: Arity::Between(0, 1),
+        func: generate_uninterned_symbol,
+    },
+];
+
+fn expect_symbol(name: &str, obj: &Object) -> Result<Symbol, String> {
+    match obj {
+        Object::Symbol(symbol) => Ok(*symbol),
+        _ => Err(format!("{} expects a symbol, but was given {}", name, obj)),
+    }
+}
+
+/// Creates a fresh symbol, whose name starts with the optional prefix in `args`
+fn uninterned(name: &str, args: &[Object]) -> Result<Object, String> {
+    let prefix = match args.first() {
+        None => "g".to_string(),
+        Some(Object::String(s)) => s.chars.borrow().clone(),
+        Some(Object::Symbol(symbol)) => symbol.name().to_string(),
+        Some(other) => {
+            return Err(format!(
+                "{} expects a string or a symbol, but was given {}",
+                name, other
+            ))
+        }
+    };
+
+    Ok(Object::Symbol(Symbol::uninterned(&prefix)))
+}
+
+fn is_symbol(args: &[Object]) -> Result<Object, String> {
+    Ok(Object::Bool(matches!(args[0], Object::Symbol(_))))
+}
+
+fn symbol_eq(args: &[Object]) -> Result<Object, String> {
+    let symbols = args
+        .iter()
+        .map(|arg| expect_symbol("symbol=?", arg))
+        .collect::<Result<Vec<Symbol>, String>>()?;
+
+    Ok(Object::Bool(
+        symbols.windows(2).all(|pair| pair[0] == pair[1]),
+    ))
+}
+
+fn symbol_to_string(args: &[Object]) -> Result<Object, String> {
+    let symbol = expect_symbol("symbol->string", &args[0])?;
+
+    // the name of a symbol cannot be changed through the string
+    Ok(Object::string_literal(symbol.name().to_string()))
+}
+
+fn string_to_symbol(args: &[Object]) -> Result<Object, String> {
+    match &args[0] {
+        Object::String(s) => Ok(Object::Symbol(Symbol::intern(&s.chars.borrow()))),
+        other => Err(format!(
+            "string->symbol expects a string, but was given {}",
+            other
+        )),
+    }
+}
+
+fn gensym(args: &[Object]) -> Result<Object, String> {
+    uninterned("gensym", args)
+}
+
+fn generate_uninterned_symbol(args: &[Object]) -> Result<Object, String> {
+    uninterned("generate-uninterned-symbol", args)
+}
@@ -0,0 +1,56 @@
+use std::cell::RefCell;
+
+use crate::object::{Arity, Native, Object};
+
+pub static NATIVES: &[Native] = &[
+    Native {
+        name: "exit",
+        arity: Arity::Between(0, 1),
+        func: exit,
+    },
+    Native {
+        name: "command-line",
+        arity: Arity::Exactly(0),
+        func: command_line,
+    },
+];
+
+thread_local! {
+    static COMMAND_LINE: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Sets the arguments returned by `(command-line)`, starting with the script name
+pub fn set_command_line(args: Vec<String>) {
+    COMMAND_LINE.with(|c| *c.borrow_mut() = args);
+}
+
+fn exit(args: &[Object]) -> Result<Object, String> {
+    let code = match args.first() {
+        None | Some(Object::Bool(true)) => 0,
+        Some(Object::Bool(false)) => 1,
+        Some(Object::Integer(n)) if (0..=255).contains(n) => *n as i32,
+        Some(other) => return Err(format!("Invalid exit code: {}", other)),
+    };
+
+    // the process is about to end, anything printed so far must not be lost
+    std::io::Write::flush(&mut std::io::stdout()).map_err(|e| e.to_string())?;
+    std::process::exit(code)
+}
+
+fn command_line(_args: &[Object]) -> Result<Object, String> {
+    Ok(Object::list(COMMAND_LINE.with(|c| {
+        c.borrow().iter().cloned().map(Object::string).collect()
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exit_codes_out_of_range() {
+        assert!(exit(&[Object::Integer(256)]).is_err());
+        assert!(exit(&[Object::Integer(-1)]).is_err());
+        assert!(exit(&[Object::Integer(4294967296)]).is_err());
+    }
+}
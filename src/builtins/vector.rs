@@ -0,0 +1,276 @@
+use std::cell::Ref;
+
+use crate::eval::apply_single;
+use crate::object::{Arity, Native, Object, Vector};
+
+use super::{expect_index, expect_range, expect_target};
+
+pub static NATIVES: &[Native] = &[
+    Native {
+        name: "vector?",
+        arity: Arity::Exactly(1),
+        func: is_vector,
+    },
+    Native {
+        name: "make-vector",
+        arity: Arity::Between(1, 2),
+        func: make_vector,
+    },
+    Native {
+        name: "vector",
+        arity: Arity::AtLeast(0),
+        func: vector,
+    },
+    Native {
+        name: "vector-length",
+        arity: Arity::Exactly(1),
+        func: vector_length,
+    },
+    Native {
+        name: "vector-ref",
+        arity: Arity::Exactly(2),
+        func: vector_ref,
+    },
+    Native {
+        name: "vector-set!",
+        arity: Arity::Exactly(3),
+        func: vector_set,
+    },
+    Native {
+        name: "vector->list",
+        arity: Arity::Between(1, 3),
+        func: vector_to_list,
+    },
+    Native {
+        name: "list->vector",
+        arity: Arity::Exactly(1),
+        func: list_to_vector,
+    },
+    Native {
+        name: "vector-fill!",
+        arity: Arity::Between(2, 4),
+        func: vector_fill,
+    },
+    Native {
+        name: "vector-map",
+        arity: Arity::AtLeast(2),
+        func: vector_map,
+    },
+    Native {
+        name: "vector-for-each",
+        arity: Arity::AtLeast(2),
+        func: vector_for_each,
+    },
+    Native {
+        name: "vector-copy",
+        arity: Arity::Between(1, 3),
+        func: vector_copy,
+    },
+    Native {
+        name: "vector-copy!",
+        arity: Arity::Between(3, 5),
+        func: vector_copy_to,
+    },
+    Native {
+        name: "vector-append",
+        arity: Arity::AtLeast(0),
+        func: vector_append,
+    },
+];
+
+/// Borrows the elements of a vector for reading
+fn expect_vector<'a>(name: &str, obj: &'a Object) -> Result<Ref<'a, Vec<Object>>, String> {
+    match obj {
+        Object::Vector(vector) => Ok(vector.items.borrow()),
+        _ => Err(format!("{} expects a vector, but was given {}", name, obj)),
+    }
+}
+
+fn expect_mutable_vector<'a>(name: &str, obj: &'a Object) -> Result<&'a Vector, String> {
+    match obj {
+        Object::Vector(vector) if vector.mutable => Ok(vector),
+        Object::Vector(_) => Err(format!("{} cannot mutate the constant {}", name, obj)),
+        _ => Err(format!("{} expects a vector, but was given {}", name, obj)),
+    }
+}
+
+/// The elements of the vector in `args[0]`, delimited by the optional start and end in `args[1..]`
+fn expect_subvector(name: &str, args: &[Object]) -> Result<Vec<Object>, String> {
+    let items = expect_vector(name, &args[0])?;
+    let (start, end) = expect_range(name, args.get(1), args.get(2), items.len())?;
+
+    Ok(items[start..end].to_vec())
+}
+
+/// Calls `function` on the elements of `vectors` taken in parallel, until the shortest one is exhausted
+fn map_vectors(name: &str, function: &Object, vectors: &[Object]) -> Result<Vec<Object>, String> {
+    // the vectors are copied, as `function` may mutate them
+    let vectors = vectors
+        .iter()
+        .map(|v| expect_vector(name, v).map(|items| items.clone()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let len = vectors.iter().map(Vec::len).min().unwrap_or(0);
+
+    (0..len)
+        .map(|i| apply_single(function, vectors.iter().map(|v| v[i].clone()).collect()))
+        .collect()
+}
+
+fn is_vector(args: &[Object]) -> Result<Object, String> {
+    Ok(Object::Bool(matches!(args[0], Object::Vector(_))))
+}
+
+fn make_vector(args: &[Object]) -> Result<Object, String> {
+    let len = match &args[0] {
+        Object::Integer(len) if *len >= 0 => *len as usize,
+        other => {
+            return Err(format!(
+                "make-vector expects a length, but was given {}",
+                other
+            ))
+        }
+    };
+    let fill = args.get(1).cloned().unwrap_or(Object::Bool(false));
+
+    Ok(Object::vector(vec![fill; len]))
+}
+
+fn vector(args: &[Object]) -> Result<Object, String> {
+    Ok(Object::vector(args.to_vec()))
+}
+
+fn vector_length(args: &[Object]) -> Result<Object, String> {
+    let items = expect_vector("vector-length", &args[0])?;
+    Ok(Object::Integer(items.len() as i64))
+}
+
+fn vector_ref(args: &[Object]) -> Result<Object, String> {
+    let items = expect_vector("vector-ref", &args[0])?;
+    let index = expect_index("vector-ref", &args[1], items.len())?;
+
+    Ok(items[index].clone())
+}
+
+fn vector_set(args: &[Object]) -> Result<Object, String> {
+    let vector = expect_mutable_vector("vector-set!", &args[0])?;
+    let mut items = vector.items.borrow_mut();
+    let index = expect_index("vector-set!", &args[1], items.len())?;
+
+    items[index] = args[2].clone();
+    Ok(Object::Unspecified)
+}
+
+fn vector_to_list(args: &[Object]) -> Result<Object, String> {
+    Ok(Object::list(expect_subvector("vector->list", args)?))
+}
+
+fn list_to_vector(args: &[Object]) -> Result<Object, String> {
+    match args[0].list_to_vec() {
+        Some(items) => Ok(Object::vector(items)),
+        None => Err(format!(
+            "list->vector expects a list, but was given {}",
+            args[0]
+        )),
+    }
+}
+
+fn vector_fill(args: &[Object]) -> Result<Object, String> {
+    let vector = expect_mutable_vector("vector-fill!", &args[0])?;
+    let mut items = vector.items.borrow_mut();
+    let (start, end) = expect_range("vector-fill!", args.get(2), args.get(3), items.len())?;
+
+    items[start..end].fill(args[1].clone());
+    Ok(Object::Unspecified)
+}
+
+fn vector_map(args: &[Object]) -> Result<Object, String> {
+    Ok(Object::vector(map_vectors(
+        "vector-map",
+        &args[0],
+        &args[1..],
+    )?))
+}
+
+fn vector_for_each(args: &[Object]) -> Result<Object, String> {
+    map_vectors("vector-for-each", &args[0], &args[1..])?;
+    Ok(Object::Unspecified)
+}
+
+fn vector_copy(args: &[Object]) -> Result<Object, String> {
+    Ok(Object::vector(expect_subvector("vector-copy", args)?))
+}
+
+/// (vector-copy! to at from [start [end]]) copies a part of `from` into `to`, starting at index `at`
+fn vector_copy_to(args: &[Object]) -> Result<Object, String> {
+    let to = expect_mutable_vector("vector-copy!", &args[0])?;
+    // collected before `to` is borrowed mutably, as `from` may be the same vector
+    let from = expect_subvector("vector-copy!", &args[2..])?;
+
+    let mut items = to.items.borrow_mut();
+    let target = expect_target("vector-copy!", &args[1], from.len(), items.len())?;
+
+    items[target].clone_from_slice(&from);
+    Ok(Object::Unspecified)
+}
+
+fn vector_append(args: &[Object]) -> Result<Object, String> {
+    let mut items = Vec::new();
+
+    for arg in args {
+        items.extend(expect_vector("vector-append", arg)?.iter().cloned());
+    }
+
+    Ok(Object::vector(items))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ints(ns: &[i64]) -> Object {
+        Object::vector(ns.iter().map(|n| Object::Integer(*n)).collect())
+    }
+
+    #[test]
+    fn indices_are_bounds_checked() {
+        let v = ints(&[1, 2, 3]);
+
+        assert_eq!(
+            vector_ref(&[v.clone(), Object::Integer(2)]),
+            Ok(Object::Integer(3))
+        );
+        assert_eq!(
+            vector_ref(&[v.clone(), Object::Integer(3)]),
+            Err("vector-ref: index 3 is out of range [0, 3)".to_string())
+        );
+        assert!(vector_set(&[v.clone(), Object::Integer(-1), Object::Nil]).is_err());
+        assert!(vector_copy(&[v.clone(), Object::Integer(2), Object::Integer(1)]).is_err());
+        assert!(vector_ref(&[ints(&[]), Object::Integer(0)]).is_err());
+    }
+
+    #[test]
+    fn literals_are_immutable() {
+        let v = Object::vector_literal(vec![Object::Integer(1)]);
+
+        assert_eq!(
+            vector_set(&[v, Object::Integer(0), Object::Nil]),
+            Err("vector-set! cannot mutate the constant #(1)".to_string())
+        );
+    }
+
+    #[test]
+    fn copy_into_itself() {
+        let v = ints(&[1, 2, 3, 4]);
+
+        vector_copy_to(&[
+            v.clone(),
+            Object::Integer(1),
+            v.clone(),
+            Object::Integer(0),
+            Object::Integer(3),
+        ])
+        .unwrap();
+        assert_eq!(v, ints(&[1, 1, 2, 3]));
+        assert!(vector_copy_to(&[v.clone(), Object::Integer(3), v.clone()]).is_err());
+    }
+}
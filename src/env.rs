@@ -2,19 +2,28 @@ use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
 
+use crate::builtins;
 use crate::object::Object;
+use crate::symbol::Symbol;
 
 pub type EnvRef = Rc<RefCell<Env>>;
 
 #[derive(Default)]
 pub struct Env {
     parent: Option<EnvRef>,
-    vars: HashMap<String, Object>,
+    vars: HashMap<Symbol, Object>,
 }
 
 impl Env {
+    /// Creates a top-level environment, in which every builtin procedure is defined
     pub fn new() -> Env {
-        Default::default()
+        let mut env: Env = Default::default();
+
+        for native in builtins::natives() {
+            env.set(Symbol::intern(native.name), Object::Native(native));
+        }
+
+        env
     }
 
     pub fn extend(parent: EnvRef) -> Env {
@@ -24,17 +33,33 @@ impl Env {
         }
     }
 
-    pub fn get(&self, name: &str) -> Option<Object> {
-        match self.vars.get(name) {
+    pub fn get(&self, symbol: Symbol) -> Option<Object> {
+        match self.vars.get(&symbol) {
             Some(value) => Some(value.clone()),
             None => self
                 .parent
                 .as_ref()
-                .and_then(|o| o.borrow().get(name).clone()),
+                .and_then(|o| o.borrow().get(symbol).clone()),
         }
     }
 
-    pub fn set(&mut self, name: &str, val: Object) {
-        self.vars.insert(name.to_string(), val);
+    /// Defines a variable in this frame, shadowing any variable of the same name in the parents
+    pub fn set(&mut self, symbol: Symbol, val: Object) {
+        self.vars.insert(symbol, val);
+    }
+
+    /// Changes the value of an existing variable, in the nearest frame where it is defined.
+    /// Returns false if the variable is unbound
+    pub fn assign(&mut self, symbol: Symbol, val: Object) -> bool {
+        match self.vars.get_mut(&symbol) {
+            Some(value) => {
+                *value = val;
+                true
+            }
+            None => match &self.parent {
+                Some(parent) => parent.borrow_mut().assign(symbol, val),
+                None => false,
+            },
+        }
     }
 }
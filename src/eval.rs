@@ -1,31 +1,93 @@
-use crate::object::Object;
+use crate::object::{CaseLambda, Formals, Lambda, Object, Pair};
 use std::cell::RefCell;
 use std::rc::Rc;
 
 use crate::env::{Env, EnvRef};
 
-use crate::parser::parse;
+use crate::parser::parse_program;
+use crate::symbol::Symbol;
 
+/// Evaluates every top-level form of a program in order, returning the value of the last one
 pub fn eval(program: &str, env: &mut EnvRef) -> Result<Object, String> {
-    match parse(program) {
-        Ok(content) => eval_obj(&content, env),
-        Err(err) => Err(format!("{}", err)),
+    let forms = match parse_program(program) {
+        Ok(forms) => forms,
+        Err(err) => return Err(format!("{}", err)),
+    };
+
+    let mut result = Err(String::new()); // empty error is just no input
+    for form in &forms {
+        result = Ok(eval_obj(form, env)?);
     }
+
+    result
+}
+
+/// The outcome of evaluating an expression: either its value, or the expression in tail position
+/// that remains to be evaluated, along with its environment
+enum Tail {
+    Value(Object),
+    Eval(Object, EnvRef),
 }
 
 fn eval_obj(obj: &Object, env: &mut EnvRef) -> Result<Object, String> {
-    match obj {
-        Object::Bool(b) => Ok(Object::Bool(*b)),
-        Object::Float(x) => Ok(Object::Float(*x)),
-        Object::Integer(n) => Ok(Object::Integer(*n)),
-        Object::Lambda(_params, _body) => Err("Lambda not yet evaluable".to_string()),
-        Object::List(list) => eval_list(list, env),
-        Object::String(s) => Ok(Object::String(s.to_string())),
-        Object::Symbol(s) => eval_symbol(s, env),
+    let mut tail = eval_tail(obj, env)?;
+
+    // expressions in tail position are evaluated by this loop rather than recursively,
+    // so that a procedure calling itself in tail position runs in constant stack space
+    loop {
+        match tail {
+            Tail::Value(value) => return Ok(value),
+            Tail::Eval(expr, mut env) => tail = eval_tail(&expr, &mut env)?,
+        }
+    }
+}
+
+fn eval_tail(obj: &Object, env: &mut EnvRef) -> Result<Tail, String> {
+    let value = match obj {
+        Object::Bool(b) => Object::Bool(*b),
+        Object::Char(c) => Object::Char(*c),
+        Object::Float(x) => Object::Float(*x),
+        Object::Integer(n) => Object::Integer(*n),
+        Object::BigInteger(_) | Object::Rational(_) | Object::Complex(_) => obj.clone(),
+        Object::Lambda(lambda) => Object::Lambda(lambda.clone()),
+        Object::CaseLambda(case_lambda) => Object::CaseLambda(case_lambda.clone()),
+        Object::Native(native) => Object::Native(native),
+        Object::Pair(_) => {
+            return match obj.list_to_vec() {
+                Some(list) => eval_list(&list, env),
+                None => Err(format!("Improper list in expression: {}", obj)),
+            }
+        }
+        Object::Vector(vector) => Object::Vector(vector.clone()),
+        Object::Bytevector(bytevector) => Object::Bytevector(bytevector.clone()),
+        Object::Values(values) => Object::Values(values.clone()),
+        Object::Nil => return Err("Empty list".to_string()),
+        Object::String(s) => Object::String(s.clone()),
+        Object::Symbol(s) => eval_symbol(*s, env)?,
+        Object::Unspecified => Object::Unspecified,
+    };
+
+    Ok(Tail::Value(value))
+}
+
+/// Fails if `value`, returned by `origin`, stands for multiple values where a single one is expected
+fn expect_single(value: Object, origin: &Object) -> Result<Object, String> {
+    match value {
+        Object::Values(values) => Err(format!(
+            "Expected a single value, but {} returned {} values",
+            origin,
+            values.len()
+        )),
+        value => Ok(value),
     }
 }
 
-fn eval_symbol(s: &str, env: &mut EnvRef) -> Result<Object, String> {
+/// Evaluates an expression whose value is used on its own, which therefore cannot return multiple values
+fn eval_single(obj: &Object, env: &mut EnvRef) -> Result<Object, String> {
+    expect_single(eval_obj(obj, env)?, obj)
+}
+
+fn eval_symbol(s: Symbol, env: &mut EnvRef) -> Result<Object, String> {
     let val = env.borrow_mut().get(s);
 
     if val.is_none() {
@@ -35,170 +97,423 @@ fn eval_symbol(s: &str, env: &mut EnvRef) -> Result<Object, String> {
     Ok(val.unwrap().clone())
 }
 
-fn eval_list(list: &Vec<Object>, env: &mut EnvRef) -> Result<Object, String> {
+fn eval_list(list: &[Object], env: &mut EnvRef) -> Result<Tail, String> {
     match eval_builtin(list, env) {
         Some(res) => res,
-        None => match list.get(0) {
-            Some(Object::Symbol(name)) => eval_function_call(name, list, env),
-            Some(_) => match list.into_iter().map(|o| eval_obj(o, env)).collect() {
-                Ok(l) => Ok(Object::List(l)),
-                Err(e) => Err(e),
-            },
+        None => match list.split_first() {
+            Some((head, args)) => {
+                let function = eval_single(head, env)?;
+
+                // not lazy, parameters are evaluated in the caller's scope
+                let args = args
+                    .iter()
+                    .map(|o| eval_single(o, env))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                apply_tail(&function, args)
+            }
             None => Err("Empty list".to_string()),
         },
     }
 }
 
-fn eval_builtin(list: &Vec<Object>, env: &mut EnvRef) -> Option<Result<Object, String>> {
-    const BINOPS: [&str; 8] = ["+", "-", "*", "/", "<", ">", "=", "!="];
-
-    match &list[..] {
-        [Object::Symbol(kw_define), Object::Symbol(s), value] if kw_define == "define" => {
-            Some(define(&s, &value, env))
+fn eval_builtin(list: &[Object], env: &mut EnvRef) -> Option<Result<Tail, String>> {
+    let value = match list {
+        [Object::Symbol(Symbol::DEFINE), Object::Symbol(s), value] => define(*s, value, env),
+        [Object::Symbol(Symbol::DEFINE), Object::Pair(target), body @ ..] => {
+            define_procedure(target, body, env)
         }
-        [Object::Symbol(op), left, right] if BINOPS.iter().any(|s| s == op) => {
-            Some(binop(op, left, right, env))
+        [Object::Symbol(Symbol::DEFINE_VALUES), formals, expr] => define_values(formals, expr, env),
+        [Object::Symbol(Symbol::SET), Object::Symbol(s), value] => assign(*s, value, env),
+        [Object::Symbol(Symbol::QUOTE), datum] => Ok(datum.clone()),
+        [Object::Symbol(Symbol::QUASIQUOTE), template] => quasiquote(template, 1, env),
+        [Object::Symbol(Symbol::LAMBDA), params, body @ ..] => {
+            make_lambda(params, body, env).map(|lambda| Object::Lambda(Rc::new(lambda)))
         }
-        [Object::Symbol(kw_if), cond, if_clause] if kw_if == "if" => {
-            Some(eval_if(cond, if_clause, env))
+        [Object::Symbol(Symbol::CASE_LAMBDA), clauses @ ..] => case_lambda(clauses, env),
+        // the forms below may leave an expression in tail position
+        _ => return eval_tail_builtin(list, env),
+    };
+
+    Some(value.map(Tail::Value))
+}
+
+/// Evaluates the special forms whose last expression is in tail position
+fn eval_tail_builtin(list: &[Object], env: &mut EnvRef) -> Option<Result<Tail, String>> {
+    match list {
+        [Object::Symbol(Symbol::LET), Object::Symbol(name), bindings, body @ ..] => {
+            Some(named_let(*name, bindings, body, env))
         }
-        [Object::Symbol(kw_if), cond, if_clause, else_clause] if kw_if == "if" => {
+        [Object::Symbol(
+            keyword @ (Symbol::LET
+            | Symbol::LET_STAR
+            | Symbol::LETREC
+            | Symbol::LETREC_STAR
+            | Symbol::LET_VALUES
+            | Symbol::LET_STAR_VALUES),
+        ), bindings, body @ ..] => Some(eval_let(*keyword, bindings, body, env)),
+        [Object::Symbol(Symbol::IF), cond, if_clause] => Some(eval_if(cond, if_clause, env)),
+        [Object::Symbol(Symbol::IF), cond, if_clause, else_clause] => {
             Some(eval_if_else(cond, if_clause, else_clause, env))
         }
-        [Object::Symbol(kw_lambda), Object::List(params_list), Object::List(body)]
-            if kw_lambda == "lambda" =>
-        {
-            let mut params = Vec::with_capacity(list.len());
-
-            for param in params_list {
-                match param {
-                    Object::Symbol(s) => params.push(s.clone()),
-                    _ => return Some(Err(format!("Invalid lambda parameter: {}", param))),
+        [Object::Symbol(Symbol::BEGIN), body @ ..] => Some(eval_body(body, env)),
+        // a special form of the wrong shape must not be taken for a procedure call
+        [Object::Symbol(
+            keyword @ (Symbol::QUOTE
+            | Symbol::QUASIQUOTE
+            | Symbol::DEFINE
+            | Symbol::IF
+            | Symbol::LAMBDA
+            | Symbol::CASE_LAMBDA
+            | Symbol::DEFINE_VALUES
+            | Symbol::SET
+            | Symbol::LET
+            | Symbol::LET_STAR
+            | Symbol::LETREC
+            | Symbol::LETREC_STAR
+            | Symbol::LET_VALUES
+            | Symbol::LET_STAR_VALUES),
+        ), ..] => Some(Err(format!(
+            "Invalid {} syntax: {}",
+            keyword,
+            Object::list(list.to_vec())
+        ))),
+        _ => None,
+    }
+}
+
+/// Reads the variables bound by a lambda or a define-values, either `args`, `(a b)` or `(a b . rest)`
+fn parse_formals(formals: &Object) -> Result<Formals, String> {
+    let mut params = Vec::new();
+    let mut current = formals.clone();
+
+    let rest = loop {
+        current = match current {
+            Object::Nil => break None,
+            Object::Symbol(rest) => break Some(rest),
+            Object::Pair(pair) => match pair.car() {
+                Object::Symbol(param) => {
+                    params.push(param);
+                    pair.cdr()
                 }
+                param => return Err(format!("Invalid parameter: {}", param)),
+            },
+            _ => return Err(format!("Invalid parameters: {}", formals)),
+        }
+    };
+
+    for (i, param) in params.iter().chain(&rest).enumerate() {
+        if params[..i].contains(param) {
+            return Err(format!("Duplicate parameter: {}", param));
+        }
+    }
+
+    Ok(Formals { params, rest })
+}
+
+/// Binds each of the values to its variable in the current frame of `env`, the arity being already checked
+fn bind_formals(formals: &Formals, mut values: Vec<Object>, env: &EnvRef) {
+    let rest = values.split_off(formals.params.len());
+
+    if let Some(rest_param) = formals.rest {
+        env.borrow_mut().set(rest_param, Object::list(rest));
+    }
+    for (p, val) in formals.params.iter().zip(values) {
+        env.borrow_mut().set(*p, val);
+    }
+}
+
+fn make_lambda(formals: &Object, body: &[Object], env: &EnvRef) -> Result<Lambda, String> {
+    if body.is_empty() {
+        return Err("lambda expects a body".to_string());
+    }
+
+    Ok(Lambda {
+        formals: parse_formals(formals)?,
+        body: body.to_vec(),
+        env: env.clone(),
+    })
+}
+
+/// Builds a case-lambda from its clauses, each of them being `(formals body...)`
+fn case_lambda(clauses: &[Object], env: &mut EnvRef) -> Result<Object, String> {
+    let clauses = clauses
+        .iter()
+        .map(|clause| match clause.list_to_vec().as_deref() {
+            Some([formals, body @ ..]) => make_lambda(formals, body, env),
+            _ => Err(format!("Invalid case-lambda clause: {}", clause)),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Object::CaseLambda(Rc::new(CaseLambda { clauses })))
+}
+
+/// Evaluates a sequence of expressions in order, leaving the last one in tail position.
+/// No frame is created here: the callers evaluate bodies in a new frame holding their definitions,
+/// which may then refer to each other as with letrec*, while begin uses the current one
+fn eval_body(body: &[Object], env: &mut EnvRef) -> Result<Tail, String> {
+    match body.split_last() {
+        Some((last, init)) => {
+            for expr in init {
+                eval_obj(expr, env)?;
             }
 
-            Some(Ok(Object::Lambda(params, body.clone())))
+            Ok(Tail::Eval(last.clone(), env.clone()))
         }
-        _ => None,
+        None => Ok(Tail::Value(Object::Unspecified)),
+    }
+}
+
+fn define(symbol: Symbol, value: &Object, env: &mut EnvRef) -> Result<Object, String> {
+    let val = eval_single(value, env)?;
+
+    env.borrow_mut().set(symbol, val);
+    Ok(Object::Unspecified)
+}
+
+/// `(define (name . formals) body...)`, where name may itself be such a form to define curried procedures
+fn define_procedure(target: &Pair, body: &[Object], env: &mut EnvRef) -> Result<Object, String> {
+    let mut name = target.car();
+    let mut formals = target.cdr();
+    let mut body = body.to_vec();
+
+    // (define ((f a) b) body...) is (define (f a) (lambda (b) body...))
+    while let Object::Pair(inner) = name {
+        let lambda = Object::cons(
+            Object::Symbol(Symbol::LAMBDA),
+            Object::cons(formals, Object::list(body)),
+        );
+
+        name = inner.car();
+        formals = inner.cdr();
+        body = vec![lambda];
+    }
+
+    match name {
+        Object::Symbol(name) => {
+            let lambda = make_lambda(&formals, &body, env)?;
+
+            env.borrow_mut().set(name, Object::Lambda(Rc::new(lambda)));
+            Ok(Object::Unspecified)
+        }
+        _ => Err(format!("Invalid procedure name: {}", name)),
+    }
+}
+
+fn define_values(formals: &Object, expr: &Object, env: &mut EnvRef) -> Result<Object, String> {
+    let formals = parse_formals(formals)?;
+    let values = eval_obj(expr, env)?.values_to_vec();
+
+    formals.arity().check("define-values", values.len())?;
+    bind_formals(&formals, values, env);
+    Ok(Object::Unspecified)
+}
+
+/// `(set! symbol value)`, which changes the variable visible from `env` instead of defining a new one
+fn assign(symbol: Symbol, value: &Object, env: &mut EnvRef) -> Result<Object, String> {
+    let val = eval_single(value, env)?;
+
+    if env.borrow_mut().assign(symbol, val) {
+        Ok(Object::Unspecified)
+    } else {
+        Err(format!("Unbound symbol {}", symbol))
     }
 }
 
-fn define(symbol: &str, value: &Object, env: &mut EnvRef) -> Result<Object, String> {
-    let val = eval_obj(value, env)?;
+/// Reads the `((variables init) ...)` bindings of a let form
+fn parse_bindings(keyword: Symbol, bindings: &Object) -> Result<Vec<(Object, Object)>, String> {
+    let list = match bindings.list_to_vec() {
+        Some(list) => list,
+        None => return Err(format!("Invalid {} bindings: {}", keyword, bindings)),
+    };
 
-    env.borrow_mut().set(&symbol, val);
-    Ok(Object::Bool(true))
+    list.iter()
+        .map(|binding| match binding.list_to_vec().as_deref() {
+            Some([variables, init]) => Ok((variables.clone(), init.clone())),
+            _ => Err(format!("Invalid {} binding: {}", keyword, binding)),
+        })
+        .collect()
 }
 
-fn binop(
-    operator: &str,
-    left: &Object,
-    right: &Object,
+/// Evaluates every form of the let family, the body being evaluated in a new frame holding the bindings
+fn eval_let(
+    keyword: Symbol,
+    bindings: &Object,
+    body: &[Object],
     env: &mut EnvRef,
-) -> Result<Object, String> {
-    let left = &eval_obj(left, env)?;
-    let right = &eval_obj(right, env)?;
-
-    match operator {
-        "+" => match (left, right) {
-            (Object::Integer(l), Object::Integer(r)) => Ok(Object::Integer(l + r)),
-            (Object::Integer(l), Object::Float(r)) => Ok(Object::Float(*l as f64 + r)),
-            (Object::Float(l), Object::Integer(r)) => Ok(Object::Float(l + *r as f64)),
-            (Object::Float(l), Object::Float(r)) => Ok(Object::Float(l + r)),
-            _ => Err(format!(
-                "Unable to apply binary operation on non-numeric values: ({} {} {})",
-                operator, left, right
-            )),
-        },
-        "-" => match (left, right) {
-            (Object::Integer(l), Object::Integer(r)) => Ok(Object::Integer(l - r)),
-            (Object::Integer(l), Object::Float(r)) => Ok(Object::Float(*l as f64 - r)),
-            (Object::Float(l), Object::Integer(r)) => Ok(Object::Float(l - *r as f64)),
-            (Object::Float(l), Object::Float(r)) => Ok(Object::Float(l - r)),
-            _ => Err(format!(
-                "Unable to apply binary operation on non-numeric values: ({} {} {})",
-                operator, left, right
-            )),
-        },
-        "*" => match (left, right) {
-            (Object::Integer(l), Object::Integer(r)) => match i64::checked_mul(*l, *r) {
-                Some(value) => Ok(Object::Integer(value)),
-                None => Err("Integer overflow".to_string()),
-            },
-            (Object::Integer(l), Object::Float(r)) => Ok(Object::Float(*l as f64 * r)),
-            (Object::Float(l), Object::Integer(r)) => Ok(Object::Float(l * *r as f64)),
-            (Object::Float(l), Object::Float(r)) => Ok(Object::Float(l * r)),
-            _ => Err(format!(
-                "Unable to apply binary operation on non-numeric values: ({} {} {})",
-                operator, left, right
-            )),
-        },
-        "/" => match (left, right) {
-            (Object::Integer(l), Object::Integer(r)) => Ok(Object::Integer(l / r)),
-            (Object::Integer(l), Object::Float(r)) => Ok(Object::Float(*l as f64 / r)),
-            (Object::Float(l), Object::Integer(r)) => Ok(Object::Float(l / *r as f64)),
-            (Object::Float(l), Object::Float(r)) => Ok(Object::Float(l / r)),
-            _ => Err(format!(
-                "Unable to apply binary operation on non-numeric values: ({} {} {})",
-                operator, left, right
-            )),
-        },
-        "<" => match (left, right) {
-            (Object::Integer(l), Object::Integer(r)) => Ok(Object::Bool(l < r)),
-            (Object::Integer(l), Object::Float(r)) => Ok(Object::Bool((*l as f64) < *r)),
-            (Object::Float(l), Object::Integer(r)) => Ok(Object::Bool(*l < *r as f64)),
-            (Object::Float(l), Object::Float(r)) => Ok(Object::Bool(l < r)),
-            _ => Err(format!(
-                "Unable to apply binary operation on non-numeric values: ({} {} {})",
-                operator, left, right
-            )),
-        },
-        ">" => match (left, right) {
-            (Object::Integer(l), Object::Integer(r)) => Ok(Object::Bool(l > r)),
-            (Object::Integer(l), Object::Float(r)) => Ok(Object::Bool((*l as f64) > *r)),
-            (Object::Float(l), Object::Integer(r)) => Ok(Object::Bool(*l > *r as f64)),
-            (Object::Float(l), Object::Float(r)) => Ok(Object::Bool(l > r)),
-            _ => Err(format!(
-                "Unable to apply binary operation on non-numeric values: ({} {} {})",
-                operator, left, right
-            )),
-        },
-        "=" => match (left, right) {
-            (Object::Integer(l), Object::Integer(r)) => Ok(Object::Bool(l == r)),
-            (Object::Integer(l), Object::Float(r)) => Ok(Object::Bool((*l as f64) == *r)),
-            (Object::Float(l), Object::Integer(r)) => Ok(Object::Bool(*l == *r as f64)),
-            (Object::Float(l), Object::Float(r)) => Ok(Object::Bool(l == r)),
-            _ => Err(format!(
-                "Unable to apply binary operation on non-numeric values: ({} {} {})",
-                operator, left, right
-            )),
+) -> Result<Tail, String> {
+    let bindings = parse_bindings(keyword, bindings)?;
+    if body.is_empty() {
+        return Err(format!("{} expects a body", keyword));
+    }
+
+    let multiple_values = matches!(keyword, Symbol::LET_VALUES | Symbol::LET_STAR_VALUES);
+    let all_formals = bindings
+        .iter()
+        .map(|(variables, _)| match variables {
+            _ if multiple_values => parse_formals(variables),
+            Object::Symbol(variable) => Ok(Formals {
+                params: vec![*variable],
+                rest: None,
+            }),
+            _ => Err(format!("Invalid {} variable: {}", keyword, variables)),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // let* and let*-values bind each variable in its own frame, so a later binding may shadow an earlier one
+    if !matches!(keyword, Symbol::LET_STAR | Symbol::LET_STAR_VALUES) {
+        let variables: Vec<Symbol> = all_formals
+            .iter()
+            .flat_map(|formals| formals.params.iter().chain(&formals.rest))
+            .copied()
+            .collect();
+
+        for (i, variable) in variables.iter().enumerate() {
+            if variables[..i].contains(variable) {
+                return Err(format!("Duplicate {} variable: {}", keyword, variable));
+            }
+        }
+    }
+
+    let mut frame = Rc::new(RefCell::new(Env::extend(env.clone())));
+
+    for ((_, init), formals) in bindings.into_iter().zip(all_formals) {
+        // only the inits of let-values and let*-values may return multiple values
+        let eval_init = if multiple_values {
+            eval_obj
+        } else {
+            eval_single
+        };
+        let value = match keyword {
+            // the inits of let are evaluated outside of the new frame, those of letrec inside it
+            Symbol::LET | Symbol::LET_VALUES => eval_init(&init, env)?,
+            Symbol::LETREC | Symbol::LETREC_STAR => eval_init(&init, &mut frame)?,
+            // each init of let* sees the variables bound before it, and each binding gets its own frame
+            _ => {
+                let value = eval_init(&init, &mut frame)?;
+                frame = Rc::new(RefCell::new(Env::extend(frame)));
+                value
+            }
+        };
+        let values = value.values_to_vec();
+
+        formals.arity().check(&keyword.to_string(), values.len())?;
+        bind_formals(&formals, values, &frame);
+    }
+
+    eval_body(body, &mut frame)
+}
+
+/// `(let name bindings body...)` calls the procedure `name`, which takes the bound variables as parameters
+/// and is visible from its own body so that it can loop
+fn named_let(
+    name: Symbol,
+    bindings: &Object,
+    body: &[Object],
+    env: &mut EnvRef,
+) -> Result<Tail, String> {
+    let bindings = parse_bindings(Symbol::LET, bindings)?;
+    let (variables, inits): (Vec<Object>, Vec<Object>) = bindings.into_iter().unzip();
+
+    let args = inits
+        .iter()
+        .map(|init| eval_single(init, env))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let frame = Rc::new(RefCell::new(Env::extend(env.clone())));
+    let lambda = Rc::new(make_lambda(&Object::list(variables), body, &frame)?);
+    frame.borrow_mut().set(name, Object::Lambda(lambda.clone()));
+
+    apply_lambda(&lambda, args)
+}
+
+/// Returns the expression of `(keyword expr)`, if `obj` has this shape
+fn special_form_arg(obj: &Object, keyword: Symbol) -> Option<Object> {
+    match obj {
+        Object::Pair(pair) => match (pair.car(), pair.cdr()) {
+            (Object::Symbol(s), Object::Pair(rest))
+                if s == keyword && rest.cdr() == Object::Nil =>
+            {
+                Some(rest.car())
+            }
+            _ => None,
         },
-        "!=" => match (left, right) {
-            (Object::Integer(l), Object::Integer(r)) => Ok(Object::Bool(l != r)),
-            (Object::Integer(l), Object::Float(r)) => Ok(Object::Bool((*l as f64) != *r)),
-            (Object::Float(l), Object::Integer(r)) => Ok(Object::Bool(*l != *r as f64)),
-            (Object::Float(l), Object::Float(r)) => Ok(Object::Bool(l != r)),
-            _ => Err(format!(
-                "Unable to apply binary operation on non-numeric values: ({} {} {})",
-                operator, left, right
-            )),
-        }, // non-standard
-        _ => unreachable!("Unknown binary operator !"),
+        _ => None,
+    }
+}
+
+/// Builds the quasiquoted template, `depth` being the current quasiquotation level
+fn quasiquote(template: &Object, depth: usize, env: &mut EnvRef) -> Result<Object, String> {
+    let pair = match template {
+        Object::Pair(pair) => pair,
+        // the elements of a vector are quasiquoted as those of a list would be
+        Object::Vector(vector) => {
+            let items = Object::list(vector.items.borrow().clone());
+
+            return match quasiquote(&items, depth, env)?.list_to_vec() {
+                Some(items) => Ok(Object::vector(items)),
+                None => Err(format!("Invalid vector in quasiquote: {}", template)),
+            };
+        }
+        _ => return Ok(template.clone()),
+    };
+
+    if let Some(expr) = special_form_arg(template, Symbol::UNQUOTE) {
+        return if depth == 1 {
+            eval_single(&expr, env)
+        } else {
+            Ok(Object::list(vec![
+                Object::Symbol(Symbol::UNQUOTE),
+                quasiquote(&expr, depth - 1, env)?,
+            ]))
+        };
+    }
+
+    if let Some(expr) = special_form_arg(template, Symbol::QUASIQUOTE) {
+        return Ok(Object::list(vec![
+            Object::Symbol(Symbol::QUASIQUOTE),
+            quasiquote(&expr, depth + 1, env)?,
+        ]));
+    }
+
+    let car = pair.car();
+    let cdr = quasiquote(&pair.cdr(), depth, env)?;
+
+    match special_form_arg(&car, Symbol::UNQUOTE_SPLICING) {
+        Some(expr) if depth == 1 => {
+            let spliced = eval_single(&expr, env)?;
+
+            match spliced.list_to_vec() {
+                Some(items) => Ok(Object::list_with_tail(items, cdr)),
+                None => Err(format!(
+                    "unquote-splicing expects a list, but was given {}",
+                    spliced
+                )),
+            }
+        }
+        Some(expr) => {
+            let car = Object::list(vec![
+                Object::Symbol(Symbol::UNQUOTE_SPLICING),
+                quasiquote(&expr, depth - 1, env)?,
+            ]);
+            Ok(Object::cons(car, cdr))
+        }
+        None => Ok(Object::cons(quasiquote(&car, depth, env)?, cdr)),
     }
 }
 
 fn eval_condition(cond: &Object, env: &mut EnvRef) -> Result<bool, String> {
-    match eval_obj(cond, env)? {
+    match eval_single(cond, env)? {
         Object::Bool(false) => Ok(false),
         _ => Ok(true),
     }
 }
 
-fn eval_if(condition: &Object, if_clause: &Object, env: &mut EnvRef) -> Result<Object, String> {
-    if eval_condition(condition, env)? == true {
-        eval_obj(if_clause, env)
+fn eval_if(condition: &Object, if_clause: &Object, env: &mut EnvRef) -> Result<Tail, String> {
+    if eval_condition(condition, env)? {
+        Ok(Tail::Eval(if_clause.clone(), env.clone()))
     } else {
-        Ok(Object::Bool(false))
+        Ok(Tail::Value(Object::Bool(false)))
     }
 }
 
@@ -207,51 +522,55 @@ fn eval_if_else(
     if_clause: &Object,
     else_clause: &Object,
     env: &mut EnvRef,
-) -> Result<Object, String> {
-    if eval_condition(condition, env)? == true {
-        eval_obj(if_clause, env)
+) -> Result<Tail, String> {
+    let clause = if eval_condition(condition, env)? {
+        if_clause
     } else {
-        eval_obj(else_clause, env)
-    }
+        else_clause
+    };
+
+    Ok(Tail::Eval(clause.clone(), env.clone()))
 }
 
-fn eval_function_call(name: &str, list: &Vec<Object>, env: &mut EnvRef) -> Result<Object, String> {
-    if list.is_empty() {
-        return Err("Empty list".to_string());
+/// Calls a procedure, be it a lambda or a native one, on already evaluated arguments
+pub fn apply(function: &Object, args: Vec<Object>) -> Result<Object, String> {
+    match apply_tail(function, args)? {
+        Tail::Value(value) => Ok(value),
+        Tail::Eval(expr, mut env) => eval_obj(&expr, &mut env),
     }
+}
 
-    let function = env.borrow_mut().get(name);
-
-    if function.is_none() {
-        return Err(format!("Unbound symbol: {}", name));
-    }
+/// Calls a procedure whose result is used on its own, as the procedure arguments of natives are
+pub fn apply_single(function: &Object, args: Vec<Object>) -> Result<Object, String> {
+    expect_single(apply(function, args)?, function)
+}
 
+/// Calls a procedure, leaving the last expression of a lambda body in tail position
+fn apply_tail(function: &Object, args: Vec<Object>) -> Result<Tail, String> {
     match function {
-        Some(Object::Lambda(params, body)) => {
-            if params.len() != list.len() - 1 {
-                return Err(format!(
-                    "Lambda expects {} parameters, but was given {}",
-                    params.len(),
-                    list.len() - 1
-                ));
-            }
-
-            let mut new_env = Rc::new(RefCell::new(Env::extend(env.clone())));
-
-            // not lazy
-            for (i, p) in params.iter().enumerate() {
-                let val = eval_obj(&list[i + 1], env)?; // evaluate parameters in the parent scope
-                new_env.borrow_mut().set(p, val);
-            }
-            eval_obj(&Object::List(body), &mut new_env)
+        Object::Native(native) => {
+            native.arity.check(native.name, args.len())?;
+            (native.func)(&args).map(Tail::Value)
         }
+        Object::Lambda(lambda) => apply_lambda(lambda, args),
+        Object::CaseLambda(case_lambda) => apply_lambda(case_lambda.select(args.len())?, args),
         _ => Err(format!(
             "Trying to evaluate non-function expression: {}",
-            name
+            function
         )),
     }
 }
 
+fn apply_lambda(lambda: &Lambda, args: Vec<Object>) -> Result<Tail, String> {
+    lambda.formals.arity().check("Lambda", args.len())?;
+
+    // the body is evaluated in a child of the environment the lambda was created in
+    let mut new_env = Rc::new(RefCell::new(Env::extend(lambda.env.clone())));
+
+    bind_formals(&lambda.formals, args, &new_env);
+    eval_body(&lambda.body, &mut new_env)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -266,93 +585,1304 @@ mod tests {
     #[test]
     fn test_area_of_a_circle() {
         let mut env = Rc::new(RefCell::new(Env::new()));
-        let program = "(
-                        (define r 10)
-                        (define pi 314)
-                        (* pi (* r r))
-                      )";
+        let program = "
+            (define r 10)
+            (define pi 314)
+            (* pi (* r r))
+        ";
         let result = eval(program, &mut env).unwrap();
-        assert_eq!(
-            result,
-            Object::List(vec![
-                Object::Bool(true),
-                Object::Bool(true),
-                Object::Integer((314 * 10 * 10) as i64)
-            ])
-        );
+        assert_eq!(result, Object::Integer(314 * 10 * 10));
     }
 
     #[test]
     fn test_sqr_function() {
         let mut env = Rc::new(RefCell::new(Env::new()));
-        let program = "(
-                        (define sqr (lambda (r) (* r r))) 
-                        (sqr 10)
-                       )";
+        let program = "
+            (define sqr (lambda (r) (* r r)))
+            (sqr 10)
+        ";
         let result = eval(program, &mut env).unwrap();
-        assert_eq!(
-            result,
-            Object::List(vec![Object::Bool(true), Object::Integer((10 * 10) as i64)])
-        );
+        assert_eq!(result, Object::Integer(10 * 10));
     }
 
     #[test]
     fn test_fibonaci() {
         let mut env = Rc::new(RefCell::new(Env::new()));
         let program = "
-            (
-                (define fib (lambda (n) (if (< n 2) 1 (+ (fib (- n 1)) (fib (- n 2))))))
-                (fib 10)
-            )
+            (define fib (lambda (n) (if (< n 2) 1 (+ (fib (- n 1)) (fib (- n 2))))))
+            (fib 10)
         ";
 
         let result = eval(program, &mut env).unwrap();
-        assert_eq!(
-            result,
-            Object::List(vec![Object::Bool(true), Object::Integer((89) as i64)])
-        );
+        assert_eq!(result, Object::Integer(89));
     }
 
     #[test]
     fn test_factorial() {
         let mut env = Rc::new(RefCell::new(Env::new()));
         let program = "
-            (
-                (define fact (lambda (n) (if (< n 1) 1 (* n (fact (- n 1))))))
-                (fact 5)
-            )
+            (define fact (lambda (n) (if (< n 1) 1 (* n (fact (- n 1))))))
+            (fact 5)
         ";
 
         let result = eval(program, &mut env).unwrap();
-        assert_eq!(
-            result,
-            Object::List(vec![Object::Bool(true), Object::Integer((120) as i64)])
-        );
+        assert_eq!(result, Object::Integer(120));
     }
 
     #[test]
     fn test_circle_area_function() {
         let mut env = Rc::new(RefCell::new(Env::new()));
         let program = "
-            (
-                (define pi 314)
-                (define r 10)
-                (define sqr (lambda (r) (* r r)))
-                (define area (lambda (r) (* pi (sqr r))))
-                (area r)
-            )
+            (define pi 314)
+            (define r 10)
+            (define sqr (lambda (r) (* r r)))
+            (define area (lambda (r) (* pi (sqr r))))
+            (area r)
+        ";
+
+        let result = eval(program, &mut env).unwrap();
+        assert_eq!(result, Object::Integer(314 * 10 * 10));
+    }
+
+    #[test]
+    fn test_closure_captures_environment() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+        let program = "(((lambda (x) (lambda (y) (+ x y))) 1) 2)";
+
+        let result = eval(program, &mut env).unwrap();
+        assert_eq!(result, Object::Integer(3));
+    }
+
+    #[test]
+    fn test_closure_outlives_call() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+        let program = "
+            (define make-adder (lambda (x) (lambda (y) (+ x y))))
+            (define add1 ((lambda (x) (lambda (y) (+ x y))) 1))
+            (define add10 (make-adder 10))
+        ";
+        eval(program, &mut env).unwrap();
+
+        assert_eq!(eval("(add1 5)", &mut env).unwrap(), Object::Integer(6));
+        assert_eq!(eval("(add10 5)", &mut env).unwrap(), Object::Integer(15));
+    }
+
+    #[test]
+    fn test_lexical_scoping() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+        let program = "
+            (define x 1)
+            (define get-x (lambda () (+ x 0)))
+            (define shadow (lambda (x) (get-x)))
+            (shadow 2)
         ";
 
         let result = eval(program, &mut env).unwrap();
+        assert_eq!(result, Object::Integer(1));
+    }
+
+    #[test]
+    fn test_program_returns_last_value() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+        let result = eval("(define a 1) (define b 2) (+ a b)", &mut env).unwrap();
+        assert_eq!(result, Object::Integer(3));
+    }
+
+    #[test]
+    fn test_empty_program() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+        assert_eq!(eval("   ", &mut env), Err(String::new()));
+    }
+
+    #[test]
+    fn test_non_function_call_errors() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+        assert!(eval("(1 2 3)", &mut env).is_err());
+    }
+
+    #[test]
+    fn test_command_line() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+        crate::builtins::set_command_line(vec!["script.scm".to_string(), "arg".to_string()]);
+
+        let result = eval("(command-line)", &mut env).unwrap();
         assert_eq!(
             result,
-            Object::List(vec![
-                Object::Bool(true),
-                Object::Bool(true),
-                Object::Bool(true),
-                Object::Bool(true),
-                Object::Integer((314 * 10 * 10) as i64)
+            Object::list(vec![Object::string("script.scm"), Object::string("arg")])
+        );
+    }
+
+    #[test]
+    fn test_invalid_exit_code_errors() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+        assert!(eval("(exit \"no\")", &mut env).is_err());
+    }
+
+    #[test]
+    fn test_boolean_literals() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+        assert_eq!(eval("(if #f 1 2)", &mut env).unwrap(), Object::Integer(2));
+        assert_eq!(eval("#true", &mut env).unwrap(), Object::Bool(true));
+    }
+
+    #[test]
+    fn test_quote() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+        assert_eq!(eval("'a", &mut env).unwrap(), Object::symbol("a"));
+        assert_eq!(
+            eval("'(1 (2 b))", &mut env).unwrap(),
+            Object::list(vec![
+                Object::Integer(1),
+                Object::list(vec![Object::Integer(2), Object::symbol("b")])
+            ])
+        );
+        assert_eq!(
+            eval("(quote (+ 1 2))", &mut env).unwrap(),
+            Object::list(vec![
+                Object::symbol("+"),
+                Object::Integer(1),
+                Object::Integer(2)
             ])
         );
+        assert_eq!(eval("''a", &mut env).unwrap().to_string(), "(quote a)");
+    }
+
+    #[test]
+    fn test_quasiquote() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+        eval("(define x 2) (define l '(3 4))", &mut env).unwrap();
+
+        assert_eq!(
+            eval("`(1 ,x ,@l 5)", &mut env).unwrap().to_string(),
+            "(1 2 3 4 5)"
+        );
+        assert_eq!(
+            eval("`(1 (,(+ x 1)))", &mut env).unwrap().to_string(),
+            "(1 (3))"
+        );
+        assert_eq!(eval("`,x", &mut env).unwrap(), Object::Integer(2));
+        assert_eq!(eval("`(,@'())", &mut env).unwrap(), Object::Nil);
+    }
+
+    #[test]
+    fn test_nested_quasiquote() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+        eval("(define x 2)", &mut env).unwrap();
+
+        // R7RS 4.2.8
+        assert_eq!(
+            eval("`(a `(b ,(c ,x)))", &mut env).unwrap().to_string(),
+            "(a (quasiquote (b (unquote (c 2)))))"
+        );
+        assert_eq!(
+            eval("`(1 `,(+ 1 ,x))", &mut env).unwrap().to_string(),
+            "(1 (quasiquote (unquote (+ 1 2))))"
+        );
+        assert_eq!(
+            eval("`(a `(b ,@(c ,@'(x y))))", &mut env)
+                .unwrap()
+                .to_string(),
+            "(a (quasiquote (b (unquote-splicing (c x y)))))"
+        );
+    }
+
+    #[test]
+    fn test_unquote_splicing_non_list_errors() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+        assert!(eval("`(1 ,@2)", &mut env).is_err());
+    }
+
+    #[test]
+    fn test_quasiquote_dotted() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+        eval("(define x 2) (define l '(3 4))", &mut env).unwrap();
+
+        assert_eq!(
+            eval("`(1 . ,x)", &mut env).unwrap(),
+            Object::cons(Object::Integer(1), Object::Integer(2))
+        );
+        assert_eq!(
+            eval("`(0 ,@l . 5)", &mut env).unwrap().to_string(),
+            "(0 3 4 . 5)"
+        );
+    }
+
+    #[test]
+    fn test_pairs() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+
+        assert_eq!(eval("(cons 1 2)", &mut env).unwrap().to_string(), "(1 . 2)");
+        assert_eq!(
+            eval("(cons 1 (cons 2 '()))", &mut env).unwrap().to_string(),
+            "(1 2)"
+        );
+        assert_eq!(eval("(car '(1 2))", &mut env).unwrap(), Object::Integer(1));
+        assert_eq!(
+            eval("(cdr '(1 2))", &mut env).unwrap(),
+            Object::list(vec![Object::Integer(2)])
+        );
+        assert_eq!(eval("(list)", &mut env).unwrap(), Object::Nil);
+        assert_eq!(
+            eval("(list 1 (+ 1 1))", &mut env).unwrap().to_string(),
+            "(1 2)"
+        );
+        assert_eq!(eval("(null? '())", &mut env).unwrap(), Object::Bool(true));
+        assert_eq!(eval("(pair? '())", &mut env).unwrap(), Object::Bool(false));
+        assert!(eval("(car '())", &mut env).is_err());
+    }
+
+    #[test]
+    fn test_shared_mutable_structure() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+        let program = "
+            (define tail (list 2 3))
+            (define a (cons 1 tail))
+            (define b (cons 0 tail))
+            (set-car! tail 20)
+            (set-cdr! (cdr tail) 4)
+        ";
+        eval(program, &mut env).unwrap();
+
+        assert_eq!(eval("a", &mut env).unwrap().to_string(), "(1 20 3 . 4)");
+        assert_eq!(eval("b", &mut env).unwrap().to_string(), "(0 20 3 . 4)");
+    }
+
+    #[test]
+    fn test_improper_list_call_errors() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+        assert!(eval("(+ 1 . 2)", &mut env).is_err());
+    }
+
+    #[test]
+    fn test_natives_are_values() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+        let program = "
+            (define add +)
+            (define twice (lambda (f x) (f x x)))
+            (list (add 1 2) (twice * 3) ((if #t - +) 5 3))
+        ";
+
+        let result = eval(program, &mut env).unwrap();
+        assert_eq!(result.to_string(), "(3 9 2)");
+        assert_eq!(
+            eval("car", &mut env).unwrap().to_string(),
+            "#<procedure car>"
+        );
+    }
+
+    #[test]
+    fn test_apply() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+
+        assert_eq!(
+            eval("(apply + '(1 2))", &mut env).unwrap(),
+            Object::Integer(3)
+        );
+        assert_eq!(
+            eval("(apply cons 1 '(2))", &mut env).unwrap().to_string(),
+            "(1 . 2)"
+        );
+        assert_eq!(
+            eval("(apply (lambda (a b) (- a b)) '(5 1))", &mut env).unwrap(),
+            Object::Integer(4)
+        );
+        assert!(eval("(apply + 1 2)", &mut env).is_err());
+    }
+
+    #[test]
+    fn test_map() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+
+        assert_eq!(
+            eval("(map + '(1 2 3) '(10 20 30))", &mut env)
+                .unwrap()
+                .to_string(),
+            "(11 22 33)"
+        );
+        assert_eq!(
+            eval("(map (lambda (x) (* x x)) '(1 2 3 4))", &mut env)
+                .unwrap()
+                .to_string(),
+            "(1 4 9 16)"
+        );
+        assert_eq!(
+            eval("(map cons '(1 2 3) '(a b))", &mut env)
+                .unwrap()
+                .to_string(),
+            "((1 . a) (2 . b))"
+        );
+    }
+
+    #[test]
+    fn test_native_arity_errors() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+
+        assert_eq!(
+            eval("(car '(1) '(2))", &mut env),
+            Err("car expects 1 arguments, but was given 2".to_string())
+        );
+    }
+
+    #[test]
+    fn test_variadic_arithmetic() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+
+        assert_eq!(eval("(+ 1 2 3)", &mut env).unwrap(), Object::Integer(6));
+        assert_eq!(eval("(- 4)", &mut env).unwrap(), Object::Integer(-4));
+        assert_eq!(eval("(*)", &mut env).unwrap(), Object::Integer(1));
+        assert_eq!(eval("(< 1 2 3)", &mut env).unwrap(), Object::Bool(true));
+        assert_eq!(
+            eval("(apply + '(1 2 3 4))", &mut env).unwrap(),
+            Object::Integer(10)
+        );
+        assert!(eval("(-)", &mut env).is_err());
+        assert!(eval("(< 1)", &mut env).is_err());
+    }
+
+    #[test]
+    fn test_errors_do_not_end_session() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+
+        assert!(eval("(/ 1 0)", &mut env).is_err());
+        assert!(eval("(car 1)", &mut env).is_err());
+        assert_eq!(eval("(+ 1 1)", &mut env).unwrap(), Object::Integer(2));
+    }
+
+    #[test]
+    fn test_big_factorial() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+        let program = "
+            (define fact (lambda (n) (if (< n 1) 1 (* n (fact (- n 1))))))
+            (fact 25)
+        ";
+
+        let result = eval(program, &mut env).unwrap();
+        assert_eq!(result.to_string(), "15511210043330985984000000");
+        assert_eq!(
+            eval("(/ (fact 25) (fact 24))", &mut env).unwrap(),
+            Object::Integer(25)
+        );
+    }
+
+    #[test]
+    fn test_exact_rationals() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+
+        assert_eq!(eval("(/ 1 3)", &mut env).unwrap().to_string(), "1/3");
+        assert_eq!(
+            eval("(+ (/ 1 3) (/ 2 3))", &mut env).unwrap(),
+            Object::Integer(1)
+        );
+        assert_eq!(
+            eval("(numerator (/ 6 4))", &mut env).unwrap(),
+            Object::Integer(3)
+        );
+        assert_eq!(
+            eval("(denominator (/ 6 4))", &mut env).unwrap(),
+            Object::Integer(2)
+        );
+        assert_eq!(
+            eval("(inexact (/ 1 4))", &mut env).unwrap(),
+            Object::Float(0.25)
+        );
+        assert_eq!(eval("(exact 2.5)", &mut env).unwrap().to_string(), "5/2");
+        assert_eq!(
+            eval("(exact? (/ 1 3))", &mut env).unwrap(),
+            Object::Bool(true)
+        );
+        assert_eq!(
+            eval("(inexact? (* 1.0 (/ 1 3)))", &mut env).unwrap(),
+            Object::Bool(true)
+        );
+    }
+
+    #[test]
+    fn test_complex_numbers() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+
+        assert_eq!(eval("(sqrt -4)", &mut env).unwrap().to_string(), "+2.0i");
+        assert_eq!(
+            eval("(* 3+4i 3-4i)", &mut env).unwrap(),
+            Object::Float(25.0)
+        );
+        assert_eq!(
+            eval("(magnitude (make-rectangular 3 4))", &mut env).unwrap(),
+            Object::Float(5.0)
+        );
+        assert_eq!(
+            eval("(real-part (make-polar 2 0))", &mut env).unwrap(),
+            Object::Integer(2)
+        );
+        assert_eq!(
+            eval("(complex? 1+2i)", &mut env).unwrap(),
+            Object::Bool(true)
+        );
+        assert_eq!(eval("(real? 1+2i)", &mut env).unwrap(), Object::Bool(false));
+        assert_eq!(
+            eval("(imag-part -i)", &mut env).unwrap(),
+            Object::Float(-1.0)
+        );
+    }
+
+    #[test]
+    fn test_number_conversions() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+
+        assert_eq!(
+            eval("(number->string 1.0)", &mut env).unwrap(),
+            Object::string("1.0")
+        );
+        assert_eq!(
+            eval("(number->string 255 16)", &mut env).unwrap(),
+            Object::string("ff")
+        );
+        assert_eq!(
+            eval("(string->number \"1e3\")", &mut env).unwrap(),
+            Object::Float(1000.0)
+        );
+        assert_eq!(
+            eval("(string->number \"ff\" 16)", &mut env).unwrap(),
+            Object::Integer(255)
+        );
+        assert_eq!(
+            eval("(string->number \"#b11\" 16)", &mut env).unwrap(),
+            Object::Integer(3)
+        );
+        assert_eq!(
+            eval("(string->number \"abc\")", &mut env).unwrap(),
+            Object::Bool(false)
+        );
+        assert_eq!(
+            eval("(string->number (number->string (/ 1 3)))", &mut env).unwrap(),
+            eval("(/ 1 3)", &mut env).unwrap()
+        );
+        assert!(eval("(number->string 1 7)", &mut env).is_err());
+    }
+
+    #[test]
+    fn test_chars() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+
+        assert_eq!(eval("#\\a", &mut env).unwrap(), Object::Char('a'));
+        assert_eq!(
+            eval("(char->integer #\\x3bb)", &mut env).unwrap(),
+            Object::Integer(0x3bb)
+        );
+        assert_eq!(
+            eval("(integer->char 32)", &mut env).unwrap(),
+            Object::Char(' ')
+        );
+        assert_eq!(
+            eval("(char-upcase #\\a)", &mut env).unwrap(),
+            Object::Char('A')
+        );
+        assert_eq!(
+            eval("(char<? #\\a #\\b #\\c)", &mut env).unwrap(),
+            Object::Bool(true)
+        );
+        assert_eq!(
+            eval("(char-whitespace? #\\tab)", &mut env).unwrap(),
+            Object::Bool(true)
+        );
+        assert_eq!(
+            eval("(char-alphabetic? #\\λ)", &mut env).unwrap(),
+            Object::Bool(true)
+        );
+        assert_eq!(
+            eval("(digit-value #\\x0664)", &mut env).unwrap(),
+            Object::Integer(4)
+        );
+        assert_eq!(eval("(char? 'a)", &mut env).unwrap(), Object::Bool(false));
+        assert!(eval("(char->integer 1)", &mut env).is_err());
+    }
+
+    #[test]
+    fn test_strings() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+
+        assert_eq!(
+            eval(r#"(string-length "a\tλ")"#, &mut env).unwrap(),
+            Object::Integer(3)
+        );
+        assert_eq!(
+            eval(r#"(string-append "ab" "" "cd")"#, &mut env).unwrap(),
+            Object::string("abcd")
+        );
+        assert_eq!(
+            eval(r#"(substring "hello" 1 3)"#, &mut env).unwrap(),
+            Object::string("el")
+        );
+        assert_eq!(
+            eval(r#"(string->list "abc" 1)"#, &mut env).unwrap(),
+            Object::list(vec![Object::Char('b'), Object::Char('c')])
+        );
+        assert_eq!(
+            eval(r#"(list->string (list #\a #\b))"#, &mut env).unwrap(),
+            Object::string("ab")
+        );
+        assert_eq!(
+            eval(r#"(string-index "hello world" char-whitespace?)"#, &mut env).unwrap(),
+            Object::Integer(5)
+        );
+        assert_eq!(
+            eval(r#"(string-index "hello" #\z)"#, &mut env).unwrap(),
+            Object::Bool(false)
+        );
+        assert_eq!(
+            eval(r#"(string<? "apple" "banana" "cherry")"#, &mut env).unwrap(),
+            Object::Bool(true)
+        );
+        assert_eq!(
+            eval(r#"(string-upcase "hello")"#, &mut env).unwrap(),
+            Object::string("HELLO")
+        );
+        assert!(eval(r#"(string-ref "abc" 3)"#, &mut env).is_err());
+        assert!(eval(r#"(substring "abc" 2 1)"#, &mut env).is_err());
+    }
+
+    #[test]
+    fn test_string_mutation() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+
+        eval(r#"(define s (make-string 3 #\a))"#, &mut env).unwrap();
+        eval(r#"(define t s)"#, &mut env).unwrap();
+        eval(r#"(string-set! s 1 #\λ)"#, &mut env).unwrap();
+        assert_eq!(eval("t", &mut env).unwrap(), Object::string("aλa"));
+
+        eval(r#"(string-fill! s #\z 2)"#, &mut env).unwrap();
+        assert_eq!(eval("s", &mut env).unwrap(), Object::string("aλz"));
+
+        eval(r#"(string-copy! s 1 "xyz" 0 2)"#, &mut env).unwrap();
+        assert_eq!(eval("s", &mut env).unwrap(), Object::string("axy"));
+
+        // overlapping copy within the same string
+        eval(r#"(string-copy! s 0 s 1)"#, &mut env).unwrap();
+        assert_eq!(eval("s", &mut env).unwrap(), Object::string("xyy"));
+
+        assert!(eval(r#"(string-copy! s 2 "ab")"#, &mut env).is_err());
+        assert!(eval(r#"(string-set! s 3 #\a)"#, &mut env).is_err());
+    }
+
+    #[test]
+    fn test_string_literals_are_immutable() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+
+        eval(r#"(define s "abc")"#, &mut env).unwrap();
+        assert_eq!(
+            eval(r#"(string-set! s 0 #\z)"#, &mut env),
+            Err(r#"string-set! cannot mutate the constant "abc""#.to_string())
+        );
+        assert!(eval(r#"(string-fill! (car '("abc")) #\z)"#, &mut env).is_err());
+
+        eval(r#"(define c (string-copy s))"#, &mut env).unwrap();
+        eval(r#"(string-set! c 0 #\z)"#, &mut env).unwrap();
+        assert_eq!(eval("c", &mut env).unwrap(), Object::string("zbc"));
+        assert_eq!(eval("s", &mut env).unwrap(), Object::string("abc"));
+    }
+
+    #[test]
+    fn test_string_identity() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+
+        eval(r#"(define s (string-copy "abc"))"#, &mut env).unwrap();
+        assert_eq!(eval("(eq? s s)", &mut env).unwrap(), Object::Bool(true));
+        assert_eq!(
+            eval("(eq? s (string-copy s))", &mut env).unwrap(),
+            Object::Bool(false)
+        );
+        assert_eq!(
+            eval("(equal? s (string-copy s))", &mut env).unwrap(),
+            Object::Bool(true)
+        );
+        assert_eq!(eval("(eqv? 2 2.0)", &mut env).unwrap(), Object::Bool(false));
+    }
+
+    #[test]
+    fn test_vectors() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+
+        assert_eq!(
+            eval("#(1 (+ 1 1))", &mut env).unwrap(),
+            Object::vector(vec![
+                Object::Integer(1),
+                Object::list(vec![
+                    Object::symbol("+"),
+                    Object::Integer(1),
+                    Object::Integer(1)
+                ])
+            ])
+        );
+
+        eval("(define v (make-vector 3 0))", &mut env).unwrap();
+        eval("(vector-set! v 1 'a)", &mut env).unwrap();
+        assert_eq!(format!("{}", eval("v", &mut env).unwrap()), "#(0 a 0)");
+        assert_eq!(
+            eval("(vector-length v)", &mut env).unwrap(),
+            Object::Integer(3)
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                eval("(vector-map + #(1 2 3) #(10 20))", &mut env).unwrap()
+            ),
+            "#(11 22)"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                eval(
+                    "(vector-append #(1) (list->vector '(2 3)) (vector))",
+                    &mut env
+                )
+                .unwrap()
+            ),
+            "#(1 2 3)"
+        );
+        assert_eq!(
+            eval("(vector->list #(1 2 3) 1 2)", &mut env).unwrap(),
+            Object::list(vec![Object::Integer(2)])
+        );
+        assert_eq!(
+            format!("{}", eval("`#(1 ,(+ 1 1) ,@(list 3 4))", &mut env).unwrap()),
+            "#(1 2 3 4)"
+        );
+
+        eval("(define v2 (vector-copy v))", &mut env).unwrap();
+        eval("(vector-fill! v2 'z 1)", &mut env).unwrap();
+        assert_eq!(format!("{}", eval("v2", &mut env).unwrap()), "#(0 z z)");
+        assert_eq!(format!("{}", eval("v", &mut env).unwrap()), "#(0 a 0)");
+
+        assert!(eval("(vector-ref v 3)", &mut env).is_err());
+        assert!(eval("(vector-set! #(1 2) 0 'a)", &mut env).is_err());
+    }
+
+    #[test]
+    fn test_bytevectors() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+
+        eval("(define b (make-bytevector 3 7))", &mut env).unwrap();
+        eval("(bytevector-u8-set! b 0 255)", &mut env).unwrap();
+        assert_eq!(
+            eval("b", &mut env).unwrap(),
+            Object::bytevector(vec![255, 7, 7])
+        );
+        assert_eq!(
+            eval("(bytevector-u8-ref #u8(1 2 3) 2)", &mut env).unwrap(),
+            Object::Integer(3)
+        );
+        assert_eq!(
+            eval(
+                "(bytevector-append #u8(1) (bytevector-copy b 1) #u8())",
+                &mut env
+            )
+            .unwrap(),
+            Object::bytevector(vec![1, 7, 7])
+        );
+
+        eval("(bytevector-copy! b 1 #u8(8 9))", &mut env).unwrap();
+        assert_eq!(
+            eval("b", &mut env).unwrap(),
+            Object::bytevector(vec![255, 8, 9])
+        );
+        assert_eq!(
+            eval("(utf8->string (string->utf8 \"λx\"))", &mut env).unwrap(),
+            Object::string("λx")
+        );
+
+        assert!(eval("(bytevector-u8-ref b 3)", &mut env).is_err());
+        assert!(eval("(bytevector-u8-set! b 0 256)", &mut env).is_err());
+        assert!(eval("(bytevector-u8-set! #u8(1) 0 2)", &mut env).is_err());
+    }
+
+    #[test]
+    fn test_symbols() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+
+        assert_eq!(
+            eval("(eq? 'abc (string->symbol \"abc\"))", &mut env).unwrap(),
+            Object::Bool(true)
+        );
+        assert_eq!(
+            eval("(symbol->string 'abc)", &mut env).unwrap(),
+            Object::string("abc")
+        );
+        assert_eq!(
+            eval("(symbol=? 'a 'a (string->symbol \"a\"))", &mut env).unwrap(),
+            Object::Bool(true)
+        );
+        assert_eq!(
+            eval("(symbol=? 'a 'b)", &mut env).unwrap(),
+            Object::Bool(false)
+        );
+        assert!(eval("(string-set! (symbol->string 'abc) 0 #\\z)", &mut env).is_err());
+    }
+
+    #[test]
+    fn test_uninterned_symbols() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+
+        eval("(define g (gensym))", &mut env).unwrap();
+        assert_eq!(eval("(eq? g g)", &mut env).unwrap(), Object::Bool(true));
+        assert_eq!(
+            eval("(eq? g (gensym))", &mut env).unwrap(),
+            Object::Bool(false)
+        );
+        assert_eq!(
+            eval("(eq? g (string->symbol (symbol->string g)))", &mut env).unwrap(),
+            Object::Bool(false)
+        );
+        assert_eq!(
+            eval("(symbol? (generate-uninterned-symbol \"tmp\"))", &mut env).unwrap(),
+            Object::Bool(true)
+        );
+
+        eval("(define h (generate-uninterned-symbol 'tmp))", &mut env).unwrap();
+        assert_eq!(
+            eval("(string-index (symbol->string h) #\\t)", &mut env).unwrap(),
+            Object::Integer(0)
+        );
+    }
+
+    #[test]
+    fn test_lambda_bodies() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+
+        assert_eq!(
+            eval("((lambda (x) x) 1)", &mut env).unwrap(),
+            Object::Integer(1)
+        );
+        assert_eq!(
+            eval("((lambda () 42))", &mut env).unwrap(),
+            Object::Integer(42)
+        );
+        assert_eq!(
+            eval("((lambda (x) \"ignored\" 'ignored (+ x 1)) 1)", &mut env).unwrap(),
+            Object::Integer(2)
+        );
+
+        let program = "
+            (define log '())
+            (define f (lambda (x) (define log (cons x log)) (* x 2)))
+            (f 1)
+        ";
+        assert_eq!(eval(program, &mut env).unwrap(), Object::Integer(2));
+
+        assert!(eval("(lambda (x))", &mut env).is_err());
+    }
+
+    #[test]
+    fn test_begin() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+
+        assert_eq!(eval("(begin 1 2 3)", &mut env).unwrap(), Object::Integer(3));
+        assert_eq!(
+            eval("(begin (define x 1) (+ x 1))", &mut env).unwrap(),
+            Object::Integer(2)
+        );
+        assert_eq!(eval("x", &mut env).unwrap(), Object::Integer(1));
+        assert_eq!(eval("(begin)", &mut env).unwrap(), Object::Unspecified);
+        assert_eq!(
+            eval("(if #t (begin 'a 'b) 'c)", &mut env).unwrap(),
+            Object::symbol("b")
+        );
+    }
+
+    #[test]
+    fn test_variadic_lambdas() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+
+        assert_eq!(
+            eval("((lambda args args) 1 2 3)", &mut env).unwrap(),
+            Object::list(vec![
+                Object::Integer(1),
+                Object::Integer(2),
+                Object::Integer(3)
+            ])
+        );
+        assert_eq!(eval("((lambda args args))", &mut env).unwrap(), Object::Nil);
+        assert_eq!(
+            eval("((lambda (a b . rest) (list a b rest)) 1 2 3 4)", &mut env).unwrap(),
+            eval("'(1 2 (3 4))", &mut env).unwrap()
+        );
+        assert_eq!(
+            eval("((lambda (a . rest) rest) 1)", &mut env).unwrap(),
+            Object::Nil
+        );
+        assert_eq!(
+            eval(
+                "(apply (lambda (a . rest) (apply + a rest)) '(1 2 3))",
+                &mut env
+            )
+            .unwrap(),
+            Object::Integer(6)
+        );
+    }
+
+    #[test]
+    fn test_lambda_arity_errors() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+
+        assert_eq!(
+            eval("((lambda (a b) a) 1)", &mut env),
+            Err("Lambda expects 2 arguments, but was given 1".to_string())
+        );
+        assert_eq!(
+            eval("((lambda (a b . rest) a) 1)", &mut env),
+            Err("Lambda expects at least 2 arguments, but was given 1".to_string())
+        );
+        assert!(eval("(lambda (a a) a)", &mut env).is_err());
+        assert!(eval("(lambda (a . a) a)", &mut env).is_err());
+        assert!(eval("(lambda (a 1) a)", &mut env).is_err());
+    }
+
+    #[test]
+    fn test_case_lambda() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+        let program = "
+            (define area
+              (case-lambda
+                ((side) (* side side))
+                ((width height) (* width height))
+                ((a b . rest) (list a b rest))))
+        ";
+        eval(program, &mut env).unwrap();
+
+        assert_eq!(eval("(area 3)", &mut env).unwrap(), Object::Integer(9));
+        assert_eq!(eval("(area 2 3)", &mut env).unwrap(), Object::Integer(6));
+        assert_eq!(
+            eval("(area 1 2 3)", &mut env).unwrap(),
+            eval("'(1 2 (3))", &mut env).unwrap()
+        );
+        assert_eq!(
+            eval("(area)", &mut env),
+            Err("case-lambda expects at least 1 arguments, but was given 0".to_string())
+        );
+
+        eval(
+            "(define sizes (case-lambda ((a) 1) ((a b c) 3) ((a b c d) 4) ((a b c d e . f) 5)))",
+            &mut env,
+        )
+        .unwrap();
+        assert_eq!(
+            eval("(sizes 1 2)", &mut env),
+            Err("case-lambda expects 1 or at least 3 arguments, but was given 2".to_string())
+        );
+        eval(
+            "(define sizes (case-lambda ((a) 1) ((a b c) 3) ((a b c d) 4)))",
+            &mut env,
+        )
+        .unwrap();
+        assert_eq!(
+            eval("(sizes)", &mut env),
+            Err("case-lambda expects 1 or between 3 and 4 arguments, but was given 0".to_string())
+        );
+        assert_eq!(
+            eval("((case-lambda))", &mut env),
+            Err(
+                "case-lambda without clauses cannot be called, but was given 0 arguments"
+                    .to_string()
+            )
+        );
+        assert_eq!(
+            eval("(procedure? area)", &mut env).unwrap(),
+            Object::Bool(true)
+        );
+    }
+
+    #[test]
+    fn test_define_returns_unspecified() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+
+        assert_eq!(eval("(define x 1)", &mut env).unwrap(), Object::Unspecified);
+        assert_eq!(
+            eval("(define (f) x)", &mut env).unwrap(),
+            Object::Unspecified
+        );
+    }
+
+    #[test]
+    fn test_define_procedure() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+        let program = "
+            (define (square x) (* x x))
+            (define (sum . xs) (apply + xs))
+            (define (first-and-rest x . rest) (list x rest))
+            (square (sum 1 2))
+        ";
+
+        assert_eq!(eval(program, &mut env).unwrap(), Object::Integer(9));
+        assert_eq!(
+            eval("(first-and-rest 1 2 3)", &mut env).unwrap(),
+            eval("'(1 (2 3))", &mut env).unwrap()
+        );
+        assert!(eval("(define (1 x) x)", &mut env).is_err());
+    }
+
+    #[test]
+    fn test_curried_define() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+        let program = "
+            (define ((adder a) b) (+ a b))
+            (define (((triple a) b) c) (list a b c))
+            ((adder 1) 2)
+        ";
+
+        assert_eq!(eval(program, &mut env).unwrap(), Object::Integer(3));
+        assert_eq!(
+            eval("(((triple 1) 2) 3)", &mut env).unwrap(),
+            eval("'(1 2 3)", &mut env).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_internal_defines() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+        let program = "
+            (define (parity n)
+              (define (even? n) (if (= n 0) #t (odd? (- n 1))))
+              (define (odd? n) (if (= n 0) #f (even? (- n 1))))
+              (define result (if (even? n) 'even 'odd))
+              result)
+            (parity 7)
+        ";
+
+        assert_eq!(eval(program, &mut env).unwrap(), Object::symbol("odd"));
+        // the internal definitions are local to the body
+        assert!(eval("result", &mut env).is_err());
+        assert!(eval("(odd? 1)", &mut env).is_err());
+
+        let program = "
+            (define x 'outer)
+            (define (shadow)
+              (define (get-x) x)
+              (define x 'inner)
+              (get-x))
+            (shadow)
+        ";
+        assert_eq!(eval(program, &mut env).unwrap(), Object::symbol("inner"));
+        assert_eq!(eval("x", &mut env).unwrap(), Object::symbol("outer"));
+    }
+
+    #[test]
+    fn test_define_values() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+
+        eval("(define-values (q r) (values 7 2))", &mut env).unwrap();
+        assert_eq!(
+            eval("(list q r)", &mut env).unwrap(),
+            eval("'(7 2)", &mut env).unwrap()
+        );
+
+        eval("(define-values (a . rest) (values 1 2 3))", &mut env).unwrap();
+        assert_eq!(eval("a", &mut env).unwrap(), Object::Integer(1));
+        assert_eq!(
+            eval("rest", &mut env).unwrap(),
+            eval("'(2 3)", &mut env).unwrap()
+        );
+
+        eval("(define-values all (values))", &mut env).unwrap();
+        assert_eq!(eval("all", &mut env).unwrap(), Object::Nil);
+
+        eval("(define-values (single) 5)", &mut env).unwrap();
+        assert_eq!(eval("single", &mut env).unwrap(), Object::Integer(5));
+
+        assert_eq!(
+            eval("(define-values (x y) (values 1 2 3))", &mut env),
+            Err("define-values expects 2 arguments, but was given 3".to_string())
+        );
+        assert_eq!(
+            eval("(call-with-values (lambda () (values 1 2)) +)", &mut env).unwrap(),
+            Object::Integer(3)
+        );
+    }
+
+    #[test]
+    fn test_values_in_single_value_contexts() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+
+        assert_eq!(
+            eval("(list (values 1 2))", &mut env),
+            Err("Expected a single value, but (values 1 2) returned 2 values".to_string())
+        );
+        assert_eq!(
+            eval("(if (values) 1 2)", &mut env),
+            Err("Expected a single value, but (values) returned 0 values".to_string())
+        );
+        assert!(eval("(define x (values 1 2))", &mut env).is_err());
+        assert!(eval("(let ((x (values 1 2))) x)", &mut env).is_err());
+        // natives calling a procedure use its result on its own too
+        assert!(eval("(map (lambda (x) (values x x)) '(1 2))", &mut env).is_err());
+        assert!(eval("(vector-map (lambda (x) (values x x)) #(1 2))", &mut env).is_err());
+        assert!(eval("(string-index \"abc\" (lambda (c) (values)))", &mut env).is_err());
+        // a single value stands for itself, and multiple values may be returned from a body
+        assert_eq!(
+            eval("(list (values 1))", &mut env).unwrap(),
+            eval("'(1)", &mut env).unwrap()
+        );
+        assert_eq!(
+            eval("((lambda () (values 1 2)))", &mut env).unwrap(),
+            Object::values(vec![Object::Integer(1), Object::Integer(2)])
+        );
+    }
+
+    #[test]
+    fn test_set() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+
+        eval("(define x 1)", &mut env).unwrap();
+        assert_eq!(eval("(set! x 2)", &mut env).unwrap(), Object::Unspecified);
+        assert_eq!(eval("x", &mut env).unwrap(), Object::Integer(2));
+
+        // assigning from a closure changes the outer variable instead of shadowing it
+        eval("(define (bump!) (set! x (+ x 1)))", &mut env).unwrap();
+        eval("(bump!) (bump!)", &mut env).unwrap();
+        assert_eq!(eval("x", &mut env).unwrap(), Object::Integer(4));
+
+        // parameters are assigned in their own frame
+        eval("(define (local x) (set! x 10) x)", &mut env).unwrap();
+        assert_eq!(eval("(local 1)", &mut env).unwrap(), Object::Integer(10));
+        assert_eq!(eval("x", &mut env).unwrap(), Object::Integer(4));
+    }
+
+    #[test]
+    fn test_set_unbound() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+
+        assert_eq!(
+            eval("(set! undefined 1)", &mut env),
+            Err("Unbound symbol undefined".to_string())
+        );
+        assert!(eval("((lambda () (set! undefined 1)))", &mut env).is_err());
+        assert!(eval("undefined", &mut env).is_err());
+    }
+
+    #[test]
+    fn test_closure_counters() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+        let program = "
+            (define (make-counter)
+              (define count 0)
+              (lambda ()
+                (set! count (+ count 1))
+                count))
+            (define c1 (make-counter))
+            (define c2 (make-counter))
+            (c1) (c1) (c2)
+        ";
+
+        assert_eq!(eval(program, &mut env).unwrap(), Object::Integer(1));
+        assert_eq!(eval("(c1)", &mut env).unwrap(), Object::Integer(3));
+        assert_eq!(eval("(c2)", &mut env).unwrap(), Object::Integer(2));
+    }
+
+    #[test]
+    fn test_closures_share_state() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+        let program = "
+            (define (make-account balance)
+              (define (deposit! amount) (set! balance (+ balance amount)) balance)
+              (define (withdraw! amount) (set! balance (- balance amount)) balance)
+              (list deposit! withdraw!))
+            (define account (make-account 100))
+            (define deposit! (car account))
+            (define withdraw! (car (cdr account)))
+            (deposit! 50)
+            (withdraw! 30)
+        ";
+
+        assert_eq!(eval(program, &mut env).unwrap(), Object::Integer(120));
+    }
+
+    #[test]
+    fn test_let() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+
+        assert_eq!(
+            eval("(let ((x 1) (y 2)) (+ x y))", &mut env).unwrap(),
+            Object::Integer(3)
+        );
+        assert_eq!(eval("(let () 5)", &mut env).unwrap(), Object::Integer(5));
+
+        // the inits are evaluated outside of the new bindings
+        eval("(define x 10)", &mut env).unwrap();
+        assert_eq!(
+            eval("(let ((x 1) (y x)) y)", &mut env).unwrap(),
+            Object::Integer(10)
+        );
+        assert_eq!(
+            eval("(let ((x 1)) (define z 2) (set! x (+ x z)) x)", &mut env).unwrap(),
+            Object::Integer(3)
+        );
+        assert_eq!(eval("x", &mut env).unwrap(), Object::Integer(10));
+        assert!(eval("z", &mut env).is_err());
+
+        assert!(eval("(let ((x)) x)", &mut env).is_err());
+        assert!(eval("(let ((1 2)) 1)", &mut env).is_err());
+        assert!(eval("(let ((x 1)))", &mut env).is_err());
+    }
+
+    #[test]
+    fn test_let_star() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+
+        assert_eq!(
+            eval(
+                "(let* ((x 1) (y (+ x 1)) (x (* y 10))) (list x y))",
+                &mut env
+            )
+            .unwrap(),
+            eval("'(20 2)", &mut env).unwrap()
+        );
+
+        // a closure sees the binding that was visible when it was created
+        assert_eq!(
+            eval("(let* ((x 1) (f (lambda () x)) (x 2)) (f))", &mut env).unwrap(),
+            Object::Integer(1)
+        );
+    }
+
+    #[test]
+    fn test_letrec() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+        let program = "
+            (letrec ((even? (lambda (n) (if (= n 0) #t (odd? (- n 1)))))
+                     (odd? (lambda (n) (if (= n 0) #f (even? (- n 1))))))
+              (list (even? 10) (odd? 10)))
+        ";
+
+        assert_eq!(
+            eval(program, &mut env).unwrap(),
+            eval("'(#t #f)", &mut env).unwrap()
+        );
+        assert_eq!(
+            eval("(letrec* ((a 1) (b (+ a 1))) (list a b))", &mut env).unwrap(),
+            eval("'(1 2)", &mut env).unwrap()
+        );
+        assert!(eval("even?", &mut env).is_err());
+    }
+
+    #[test]
+    fn test_let_values() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+
+        assert_eq!(
+            eval(
+                "(let-values (((a b) (values 1 2)) ((c . d) (values 3 4 5))) (list a b c d))",
+                &mut env
+            )
+            .unwrap(),
+            eval("'(1 2 3 (4 5))", &mut env).unwrap()
+        );
+        assert_eq!(
+            eval(
+                "(let*-values (((a b) (values 1 2)) ((c) (values (+ a b)))) c)",
+                &mut env
+            )
+            .unwrap(),
+            Object::Integer(3)
+        );
+        assert_eq!(
+            eval("(let-values ((all (values 1 2))) all)", &mut env).unwrap(),
+            eval("'(1 2)", &mut env).unwrap()
+        );
+        assert_eq!(
+            eval("(let-values (((a b) (values 1))) a)", &mut env),
+            Err("let-values expects 2 arguments, but was given 1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_let_duplicate_variables() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+
+        assert_eq!(
+            eval("(let ((x 1) (x 2)) x)", &mut env),
+            Err("Duplicate let variable: x".to_string())
+        );
+        assert!(eval("(letrec ((f 1) (f 2)) f)", &mut env).is_err());
+        assert!(eval("(let-values (((a b) (values 1 2)) ((b) 3)) a)", &mut env).is_err());
+        assert!(eval("(let loop ((x 1) (x 2)) x)", &mut env).is_err());
+        // let* binds its variables one after the other
+        assert_eq!(
+            eval("(let* ((x 1) (x (+ x 1))) x)", &mut env).unwrap(),
+            Object::Integer(2)
+        );
+    }
+
+    #[test]
+    fn test_named_let() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+        let program = "
+            (let loop ((i 0) (acc '()))
+              (if (= i 5)
+                  acc
+                  (loop (+ i 1) (cons i acc))))
+        ";
+
+        assert_eq!(
+            eval(program, &mut env).unwrap(),
+            eval("'(4 3 2 1 0)", &mut env).unwrap()
+        );
+
+        let program = "
+            (define (fact n)
+              (let iter ((n n) (acc 1))
+                (if (= n 0) acc (iter (- n 1) (* acc n)))))
+            (fact 20)
+        ";
+        assert_eq!(
+            eval(program, &mut env).unwrap(),
+            Object::Integer(2432902008176640000)
+        );
+
+        // the name is only visible from the body
+        assert!(eval("loop", &mut env).is_err());
+        assert!(eval("(let loop ((i 0)))", &mut env).is_err());
+    }
+
+    #[test]
+    fn test_invalid_special_forms() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+
+        assert_eq!(
+            eval("(if)", &mut env),
+            Err("Invalid if syntax: (if)".to_string())
+        );
+        assert_eq!(
+            eval("(lambda)", &mut env),
+            Err("Invalid lambda syntax: (lambda)".to_string())
+        );
+        assert_eq!(
+            eval("(define x 1 2)", &mut env),
+            Err("Invalid define syntax: (define x 1 2)".to_string())
+        );
+        assert_eq!(
+            eval("(set! 1 2)", &mut env),
+            Err("Invalid set! syntax: (set! 1 2)".to_string())
+        );
+        assert!(eval("(quote)", &mut env).is_err());
+        assert!(eval("(let)", &mut env).is_err());
+    }
+
+    #[test]
+    fn test_tail_calls() {
+        let mut env = Rc::new(RefCell::new(Env::new()));
+
+        // each of these would overflow the 2 MB stack of a test thread if tail calls used one
+        let program = "(let loop ((i 0)) (if (< i 100000) (loop (+ i 1)) i))";
+        assert_eq!(eval(program, &mut env).unwrap(), Object::Integer(100000));
+
+        let program = "
+            (define (even? n) (if (= n 0) #t (odd? (- n 1))))
+            (define (odd? n) (if (= n 0) #f (even? (- n 1))))
+            (even? 20001)
+        ";
+        assert_eq!(eval(program, &mut env).unwrap(), Object::Bool(false));
+
+        let program = "
+            (define (count n) (let ((m (- n 1))) (begin (if (= m 0) 'done (count m)))))
+            (count 20000)
+        ";
+        assert_eq!(eval(program, &mut env).unwrap(), Object::symbol("done"));
     }
 }
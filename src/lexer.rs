@@ -1,26 +1,51 @@
 use std::fmt;
 use std::str::Chars;
 
+use crate::number::parse_number;
+use crate::object::{write_string, Object};
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum Token {
-    Integer(i64),
-    Float(f64),
+    Number(Object),
+    Bool(bool),
+    Char(char),
     String(String),
     Symbol(String),
     LParen,
     RParen,
+    /// `#(`, the start of a vector literal
+    VectorStart,
+    /// `#u8(`, the start of a bytevector literal
+    BytevectorStart,
+    /// The `.` of a dotted pair
+    Dot,
+    Quote,
+    Quasiquote,
+    Unquote,
+    UnquoteSplicing,
+    /// `#;`, the next datum is commented out
+    DatumComment,
     LexerError(String),
 }
 
 impl fmt::Display for Token {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            Token::Integer(n) => write!(f, "{}", n),
-            Token::Float(n) => write!(f, "{}", n),
-            Token::String(s) => write!(f, "\"{}\"", s),
+            Token::Number(n) => write!(f, "{}", n),
+            Token::Bool(b) => write!(f, "{}", if *b { "#t" } else { "#f" }),
+            Token::Char(c) => write!(f, "{}", Object::Char(*c)),
+            Token::String(s) => write_string(f, s),
             Token::Symbol(s) => write!(f, "{}", s),
             Token::LParen => write!(f, "("),
             Token::RParen => write!(f, ")"),
+            Token::VectorStart => write!(f, "#("),
+            Token::BytevectorStart => write!(f, "#u8("),
+            Token::Dot => write!(f, "."),
+            Token::Quote => write!(f, "'"),
+            Token::Quasiquote => write!(f, "`"),
+            Token::Unquote => write!(f, ","),
+            Token::UnquoteSplicing => write!(f, ",@"),
+            Token::DatumComment => write!(f, "#;"),
             Token::LexerError(s) => write!(f, "{}", s),
         }
     }
@@ -34,140 +59,363 @@ pub struct TokenIterator<'a> {
 }
 
 pub fn tokenize<'a>(s: &'a str) -> TokenIterator<'a> {
+    // a shebang on the first line (#!/usr/bin/env r-scheme) is not part of the program
+    let (s, line_count) = match s.strip_prefix("#!") {
+        Some(rest) => match rest.find('\n') {
+            Some(i) => (&rest[i + 1..], 2),
+            None => ("", 1),
+        },
+        None => (s, 1),
+    };
+
     TokenIterator {
         chars: s.chars().peekable(),
         is_error: false,
         c_count: 1,
-        line_count: 1,
+        line_count,
     }
 }
 
-impl Iterator for TokenIterator<'_> {
-    type Item = Token;
+impl TokenIterator<'_> {
+    fn peek(&mut self) -> Option<char> {
+        self.chars.peek().copied()
+    }
 
-    fn next(&mut self) -> Option<Token> {
-        if self.is_error {
-            return None;
+    /// Consumes the next char, keeping track of the current line and column
+    fn advance(&mut self) -> Option<char> {
+        let c = self.chars.next()?;
+
+        if c == '\n' {
+            self.line_count += 1;
+            self.c_count = 1;
+        } else {
+            self.c_count += 1;
         }
 
-        let mut current = self.chars.peek();
+        Some(c)
+    }
+
+    fn error(&mut self, message: &str) -> Option<Token> {
+        self.is_error = true;
 
-        // consume every possible whitespace char, advancing to the next valid char
-        while let Some(&c) = current {
-            if !c.is_whitespace() {
-                if c == '\n' {
-                    self.line_count += 1;
-                    self.c_count = 0;
+        Some(Token::LexerError(format!(
+            "{} (line {}, column {})",
+            message, self.line_count, self.c_count
+        )))
+    }
+
+    /// Skips whitespace and line comments
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek() {
+            if c == ';' {
+                while let Some(c) = self.advance() {
+                    if c == '\n' {
+                        break;
+                    }
+                }
+            } else if c.is_whitespace() {
+                self.advance();
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Skips a block comment, whose opening `#` has already been consumed.
+    /// Returns false if the comment is never closed
+    fn skip_block_comment(&mut self) -> bool {
+        // opening |
+        self.advance();
+        let mut depth = 1;
+
+        while let Some(c) = self.advance() {
+            match (c, self.peek()) {
+                ('|', Some('#')) => {
+                    self.advance();
+                    depth -= 1;
+
+                    if depth == 0 {
+                        return true;
+                    }
+                }
+                ('#', Some('|')) => {
+                    self.advance();
+                    depth += 1;
                 }
+                _ => (),
+            }
+        }
+
+        false
+    }
 
+    /// Reads chars until the next delimiter, without consuming it
+    fn read_atom(&mut self, token: &mut String) {
+        while let Some(c) = self.peek() {
+            if is_delimiter(c) {
                 break;
             }
 
-            // current is whitespace, we consume it and peek to the next value
-            self.chars.next();
-            self.c_count += 1;
-            current = self.chars.peek();
+            token.push(c);
+            self.advance();
         }
+    }
 
+    fn read_string(&mut self) -> Option<Token> {
         let mut token = String::with_capacity(30);
-        let mut in_string = false;
 
-        // read token
-        while let Some(&c) = current {
-            if in_string {
-                if c == '\n' {
-                    self.line_count += 1;
-                }
+        // opening quote
+        self.advance();
 
-                if c == '"' {
-                    // end of the string
-                    self.chars.next();
-                    self.c_count += 1;
-                    current = self.chars.peek();
-
-                    if let Some(&c) = current {
-                        // we check that the string is not immediately followed by another token, which is an error
-                        if !c.is_whitespace() && c != '(' && c != ')' {
-                            self.is_error = true;
-                            return Some(Token::LexerError(format!(
-                                "Unexpected character {} (line {}, column {})",
-                                c, self.line_count, self.c_count
-                            )));
-                        }
+        while let Some(c) = self.advance() {
+            if c == '"' {
+                // we check that the string is not immediately followed by another token, which is an error
+                if let Some(c) = self.peek() {
+                    if !is_delimiter(c) {
+                        return self.error(&format!("Unexpected character {}", c));
                     }
-
-                    // at this point the string literal is valid
-                    return Some(Token::String(token));
                 }
 
-                token.push(c);
-                self.chars.next();
-                self.c_count += 1;
-                current = self.chars.peek();
-            } else {
-                if c.is_whitespace() {
-                    break;
+                // at this point the string literal is valid
+                return Some(Token::String(token));
+            }
+
+            if c == '\\' {
+                match self.read_escape() {
+                    Ok(Some(c)) => token.push(c),
+                    Ok(None) => (),
+                    Err(message) => return self.error(&message),
                 }
-                if c == '(' || c == ')' {
-                    // alone paren is a token by itself
-                    // we consume it only if it’s alone
-                    if token.is_empty() {
-                        token.push(c);
-                        self.chars.next();
+            } else {
+                token.push(c);
+            }
+        }
+
+        self.error("Unexpected EOF")
+    }
+
+    /// Reads an escape sequence inside a string literal, whose backslash has already been consumed.
+    /// Returns None for a line continuation, which stands for no char at all
+    fn read_escape(&mut self) -> Result<Option<char>, String> {
+        let c = match self.advance() {
+            Some(c) => c,
+            None => return Err("Unexpected EOF".to_string()),
+        };
+
+        let escaped = match c {
+            'a' => '\u{7}',
+            'b' => '\u{8}',
+            't' => '\t',
+            'n' => '\n',
+            'r' => '\r',
+            '"' | '\\' | '|' => c,
+            'x' | 'X' => {
+                let mut hex = String::new();
+                loop {
+                    match self.advance() {
+                        Some(';') => break,
+                        Some(c) if c.is_ascii_hexdigit() => hex.push(c),
+                        _ => return Err(format!("Invalid hex escape: \\x{}", hex)),
                     }
-                    break;
                 }
 
-                if c == '"' {
-                    // beginning of a string
-
-                    if token.is_empty() {
-                        in_string = true;
-                        self.chars.next();
-                        self.c_count += 1;
-                        current = self.chars.peek();
-                        continue;
-                    } else {
-                        self.is_error = true;
-
-                        return Some(Token::LexerError(format!(
-                            "Unexpected character \" (line {}, column {})",
-                            self.line_count, self.c_count
-                        )));
+                return u32::from_str_radix(&hex, 16)
+                    .ok()
+                    .and_then(char::from_u32)
+                    .map(Some)
+                    .ok_or(format!("Invalid hex escape: \\x{};", hex));
+            }
+            // \<intraline whitespace>*<newline><intraline whitespace>* is a line continuation
+            c if c.is_whitespace() => {
+                let mut c = c;
+                while c != '\n' {
+                    match self.advance() {
+                        Some(next) if next.is_whitespace() => c = next,
+                        _ => return Err("Invalid line continuation".to_string()),
                     }
                 }
+                while self.peek().is_some_and(|c| c.is_whitespace() && c != '\n') {
+                    self.advance();
+                }
 
-                //part of a normal token
-                token.push(c);
-                self.chars.next();
-                self.c_count += 1;
-                current = self.chars.peek();
+                return Ok(None);
             }
+            _ => return Err(format!("Invalid escape sequence: \\{}", c)),
+        };
+
+        Ok(Some(escaped))
+    }
+
+    /// Reads a character literal, whose `#` has already been consumed
+    fn read_char(&mut self) -> Option<Token> {
+        // backslash
+        self.advance();
+
+        // the first char is taken even if it is a delimiter, as in #\( or #\space
+        let mut token = match self.advance() {
+            Some(c) => c.to_string(),
+            None => return self.error("Unexpected EOF"),
+        };
+        self.read_atom(&mut token);
+
+        let mut chars = token.chars();
+        let first = chars.next().unwrap();
+        if chars.as_str().is_empty() {
+            return Some(Token::Char(first));
         }
 
-        // check that the string literal has been closed
-        if in_string {
-            return Some(Token::LexerError(format!(
-                "Unexpected EOF (line {}, column {})",
-                self.line_count, self.c_count
-            )));
+        if let Some((_, c)) = CHAR_NAMES.iter().find(|(name, _)| *name == token) {
+            return Some(Token::Char(*c));
         }
 
-        //parse token
-        if token.is_empty() {
-            return None;
+        let scalar = token
+            .strip_prefix(['x', 'X'])
+            .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+            .and_then(char::from_u32);
+
+        match scalar {
+            Some(c) => Some(Token::Char(c)),
+            None => self.error(&format!("Unknown character name: #\\{}", token)),
         }
+    }
+
+    /// Reads any of the #-prefixed syntaxes
+    fn read_hash(&mut self) -> Option<Token> {
+        let mut token = String::with_capacity(8);
+        token.push('#');
+        self.advance();
+
+        match self.peek() {
+            Some('|') => {
+                if !self.skip_block_comment() {
+                    return self.error("Unterminated block comment");
+                }
+
+                return self.next();
+            }
+            Some(';') => {
+                self.advance();
+                return Some(Token::DatumComment);
+            }
+            Some('\\') => return self.read_char(),
+            Some('(') => {
+                self.advance();
+                return Some(Token::VectorStart);
+            }
+            _ => (),
+        }
+
+        self.read_atom(&mut token);
 
         match token.as_str() {
-            "(" => return Some(Token::LParen),
-            ")" => return Some(Token::RParen),
+            "#t" | "#true" => Some(Token::Bool(true)),
+            "#f" | "#false" => Some(Token::Bool(false)),
+            "#u8" if self.peek() == Some('(') => {
+                self.advance();
+                Some(Token::BytevectorStart)
+            }
+            _ => match token[1..].chars().next() {
+                Some('x' | 'X' | 'o' | 'O' | 'b' | 'B' | 'd' | 'D' | 'e' | 'E' | 'i' | 'I') => {
+                    match parse_number(&token, 10) {
+                        Some(n) => Some(Token::Number(n)),
+                        None => self.error(&format!("Invalid number literal: {}", token)),
+                    }
+                }
+                _ => self.error(&format!("Invalid # syntax: {}", token)),
+            },
+        }
+    }
+}
+
+/// Names of the characters that can be written `#\name`
+pub const CHAR_NAMES: [(&str, char); 9] = [
+    ("alarm", '\u{7}'),
+    ("backspace", '\u{8}'),
+    ("delete", '\u{7f}'),
+    ("escape", '\u{1b}'),
+    ("newline", '\n'),
+    ("null", '\0'),
+    ("return", '\r'),
+    ("space", ' '),
+    ("tab", '\t'),
+];
+
+/// Whether the token starts like a number, in which case it cannot be a symbol
+fn is_numeric_start(token: &str) -> bool {
+    let mut chars = token.chars();
+
+    match chars.next() {
+        Some(c) if c.is_ascii_digit() => true,
+        Some('+' | '-') => match chars.next() {
+            Some('.') => chars.next().is_some_and(|c| c.is_ascii_digit()),
+            Some(c) => c.is_ascii_digit(),
+            None => false,
+        },
+        Some('.') => chars.next().is_some_and(|c| c.is_ascii_digit()),
+        _ => false,
+    }
+}
+
+/// Chars that end an atom (symbol, number, ...)
+fn is_delimiter(c: char) -> bool {
+    c.is_whitespace() || c == '(' || c == ')' || c == '"' || c == ';'
+}
+
+impl Iterator for TokenIterator<'_> {
+    type Item = Token;
+
+    fn next(&mut self) -> Option<Token> {
+        if self.is_error {
+            return None;
+        }
+
+        self.skip_whitespace();
+
+        match self.peek()? {
+            '(' => {
+                self.advance();
+                Some(Token::LParen)
+            }
+            ')' => {
+                self.advance();
+                Some(Token::RParen)
+            }
+            '\'' => {
+                self.advance();
+                Some(Token::Quote)
+            }
+            '`' => {
+                self.advance();
+                Some(Token::Quasiquote)
+            }
+            ',' => {
+                self.advance();
+
+                if self.peek() == Some('@') {
+                    self.advance();
+                    Some(Token::UnquoteSplicing)
+                } else {
+                    Some(Token::Unquote)
+                }
+            }
+            '"' => self.read_string(),
+            '#' => self.read_hash(),
             _ => {
-                if let Ok(i) = token.parse::<i64>() {
-                    return Some(Token::Integer(i));
-                } else if let Ok(f) = token.parse::<f64>() {
-                    return Some(Token::Float(f));
+                let mut token = String::with_capacity(30);
+                self.read_atom(&mut token);
+
+                if self.peek() == Some('"') {
+                    return self.error("Unexpected character \"");
+                }
+
+                if token == "." {
+                    Some(Token::Dot)
+                } else if let Some(n) = parse_number(&token, 10) {
+                    Some(Token::Number(n))
+                } else if is_numeric_start(&token) {
+                    self.error(&format!("Invalid number literal: {}", token))
                 } else {
-                    return Some(Token::Symbol(token));
+                    Some(Token::Symbol(token))
                 }
             }
         }
@@ -183,14 +431,14 @@ mod test {
     fn integer_parses_correctly() {
         let tokens: Vec<Token> = tokenize("42").collect();
 
-        assert_eq!(tokens, vec![Token::Integer(42)]);
+        assert_eq!(tokens, vec![Token::Number(Object::Integer(42))]);
     }
 
     #[test]
     fn float_parses_correctly() {
         let tokens: Vec<Token> = tokenize("42.42").collect();
 
-        assert_eq!(tokens, vec![Token::Float(42.42)]);
+        assert_eq!(tokens, vec![Token::Number(Object::Float(42.42))]);
     }
 
     //todo: add tests for string
@@ -265,7 +513,7 @@ mod test {
             vec![
                 Token::LParen,
                 Token::Symbol("add".to_string()),
-                Token::Integer(1),
+                Token::Number(Object::Integer(1)),
                 Token::LParen,
                 Token::Symbol("convert".to_string()),
                 Token::LParen,
@@ -276,4 +524,465 @@ mod test {
             ]
         );
     }
+
+    #[test]
+    fn shebang_is_ignored() {
+        let tokens: Vec<Token> = tokenize("#!/usr/bin/env r-scheme\n(exit 0)").collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::LParen,
+                Token::Symbol("exit".to_string()),
+                Token::Number(Object::Integer(0)),
+                Token::RParen,
+            ]
+        );
+    }
+
+    #[test]
+    fn shebang_only_parses_empty() {
+        let tokens: Vec<Token> = tokenize("#!/usr/bin/env r-scheme").collect();
+
+        assert_eq!(tokens, vec![]);
+    }
+
+    #[test]
+    fn booleans_parse_correctly() {
+        let tokens: Vec<Token> = tokenize("#t #f #true #false").collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Bool(true),
+                Token::Bool(false),
+                Token::Bool(true),
+                Token::Bool(false),
+            ]
+        );
+    }
+
+    #[test]
+    fn boolean_in_list_parses_correctly() {
+        let tokens: Vec<Token> = tokenize("(#t)").collect();
+
+        assert_eq!(
+            tokens,
+            vec![Token::LParen, Token::Bool(true), Token::RParen]
+        );
+    }
+
+    #[test]
+    fn invalid_hash_syntax_parses_error() {
+        let tokens: Vec<Token> = tokenize("#truthy").collect();
+
+        assert_eq!(
+            tokens,
+            vec![Token::LexerError(
+                "Invalid # syntax: #truthy (line 1, column 8)".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn line_count_is_tracked() {
+        let tokens: Vec<Token> = tokenize("(a\n  b\"c\")").collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::LParen,
+                Token::Symbol("a".to_string()),
+                Token::LexerError("Unexpected character \" (line 2, column 4)".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn line_comment_is_ignored() {
+        let tokens: Vec<Token> = tokenize("; a comment\n(a ; another one\n b)").collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::LParen,
+                Token::Symbol("a".to_string()),
+                Token::Symbol("b".to_string()),
+                Token::RParen,
+            ]
+        );
+    }
+
+    #[test]
+    fn line_comment_ends_symbol() {
+        let tokens: Vec<Token> = tokenize("a;comment").collect();
+
+        assert_eq!(tokens, vec![Token::Symbol("a".to_string())]);
+    }
+
+    #[test]
+    fn block_comment_is_ignored() {
+        let tokens: Vec<Token> = tokenize("(a #| some\ncomment |# b)").collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::LParen,
+                Token::Symbol("a".to_string()),
+                Token::Symbol("b".to_string()),
+                Token::RParen,
+            ]
+        );
+    }
+
+    #[test]
+    fn nested_block_comment_is_ignored() {
+        let tokens: Vec<Token> = tokenize("#| outer #| inner |# still outer |# a").collect();
+
+        assert_eq!(tokens, vec![Token::Symbol("a".to_string())]);
+    }
+
+    #[test]
+    fn unterminated_block_comment_parses_error() {
+        let tokens: Vec<Token> = tokenize("#| outer #| inner |#").collect();
+
+        assert_eq!(
+            tokens,
+            vec![Token::LexerError(
+                "Unterminated block comment (line 1, column 21)".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn datum_comment_parses_correctly() {
+        let tokens: Vec<Token> = tokenize("#;(a) b").collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::DatumComment,
+                Token::LParen,
+                Token::Symbol("a".to_string()),
+                Token::RParen,
+                Token::Symbol("b".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn line_count_is_tracked_across_comments() {
+        let tokens: Vec<Token> = tokenize("; first\n#| second\nthird |# a\"b\"").collect();
+
+        assert_eq!(
+            tokens,
+            vec![Token::LexerError(
+                "Unexpected character \" (line 3, column 11)".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn quotes_parse_correctly() {
+        let tokens: Vec<Token> = tokenize("'a `(b ,c ,@d)").collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Quote,
+                Token::Symbol("a".to_string()),
+                Token::Quasiquote,
+                Token::LParen,
+                Token::Symbol("b".to_string()),
+                Token::Unquote,
+                Token::Symbol("c".to_string()),
+                Token::UnquoteSplicing,
+                Token::Symbol("d".to_string()),
+                Token::RParen,
+            ]
+        );
+    }
+
+    #[test]
+    fn dot_parses_correctly() {
+        let tokens: Vec<Token> = tokenize("(a . b)").collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::LParen,
+                Token::Symbol("a".to_string()),
+                Token::Dot,
+                Token::Symbol("b".to_string()),
+                Token::RParen,
+            ]
+        );
+    }
+
+    #[test]
+    fn dot_inside_symbol_parses_as_symbol() {
+        let tokens: Vec<Token> = tokenize("a.b ...").collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Symbol("a.b".to_string()),
+                Token::Symbol("...".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn big_integer_parses_correctly() {
+        let tokens: Vec<Token> = tokenize("123456789012345678901234567890").collect();
+
+        assert_eq!(
+            tokens,
+            vec![Token::Number(crate::number::from_bigint(
+                "123456789012345678901234567890".parse().unwrap()
+            ))]
+        );
+    }
+
+    #[test]
+    fn complex_parses_correctly() {
+        let tokens: Vec<Token> = tokenize("(3+4i -i)").collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::LParen,
+                Token::Number(Object::Complex(num_complex::Complex64::new(3.0, 4.0))),
+                Token::Number(Object::Complex(num_complex::Complex64::new(0.0, -1.0))),
+                Token::RParen,
+            ]
+        );
+    }
+
+    #[test]
+    fn prefixed_numbers_parse_correctly() {
+        let tokens: Vec<Token> = tokenize("(#xff #b-101 #e1.5 #i1/2)").collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::LParen,
+                Token::Number(Object::Integer(255)),
+                Token::Number(Object::Integer(-5)),
+                Token::Number(parse_number("3/2", 10).unwrap()),
+                Token::Number(Object::Float(0.5)),
+                Token::RParen,
+            ]
+        );
+    }
+
+    #[test]
+    fn special_floats_parse_correctly() {
+        let tokens: Vec<Token> = tokenize("+inf.0 -inf.0 inf nan").collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Number(Object::Float(f64::INFINITY)),
+                Token::Number(Object::Float(f64::NEG_INFINITY)),
+                Token::Symbol("inf".to_string()),
+                Token::Symbol("nan".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn peculiar_symbols_parse_correctly() {
+        let tokens: Vec<Token> = tokenize("+ - ... -> +a").collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Symbol("+".to_string()),
+                Token::Symbol("-".to_string()),
+                Token::Symbol("...".to_string()),
+                Token::Symbol("->".to_string()),
+                Token::Symbol("+a".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn malformed_number_parses_error() {
+        let tokens: Vec<Token> = tokenize("(+ 1 12abc)").collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::LParen,
+                Token::Symbol("+".to_string()),
+                Token::Number(Object::Integer(1)),
+                Token::LexerError("Invalid number literal: 12abc (line 1, column 11)".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn malformed_prefixed_number_parses_error() {
+        let tokens: Vec<Token> = tokenize("#x1g").collect();
+
+        assert_eq!(
+            tokens,
+            vec![Token::LexerError(
+                "Invalid number literal: #x1g (line 1, column 5)".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn chars_parse_correctly() {
+        let tokens: Vec<Token> = tokenize("(#\\a #\\Z #\\λ #\\( #\\) #\\  #\\x)").collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::LParen,
+                Token::Char('a'),
+                Token::Char('Z'),
+                Token::Char('λ'),
+                Token::Char('('),
+                Token::Char(')'),
+                Token::Char(' '),
+                Token::Char('x'),
+                Token::RParen,
+            ]
+        );
+    }
+
+    #[test]
+    fn named_chars_parse_correctly() {
+        let tokens: Vec<Token> =
+            tokenize("#\\space #\\newline #\\tab #\\x41 #\\x3bb #\\null").collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Char(' '),
+                Token::Char('\n'),
+                Token::Char('\t'),
+                Token::Char('A'),
+                Token::Char('λ'),
+                Token::Char('\0'),
+            ]
+        );
+    }
+
+    #[test]
+    fn unknown_char_name_parses_error() {
+        let tokens: Vec<Token> = tokenize("#\\spaces").collect();
+
+        assert_eq!(
+            tokens,
+            vec![Token::LexerError(
+                "Unknown character name: #\\spaces (line 1, column 9)".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn invalid_scalar_value_parses_error() {
+        let tokens: Vec<Token> = tokenize("#\\xd800").collect();
+
+        assert_eq!(
+            tokens,
+            vec![Token::LexerError(
+                "Unknown character name: #\\xd800 (line 1, column 8)".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn string_escapes_parse_correctly() {
+        let tokens: Vec<Token> = tokenize(r#""a\"b" "\\\n\t" "\x41;\x3bb;" "\a\|""#).collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::String("a\"b".to_string()),
+                Token::String("\\\n\t".to_string()),
+                Token::String("Aλ".to_string()),
+                Token::String("\u{7}|".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn line_continuation_parses_correctly() {
+        let tokens: Vec<Token> = tokenize("\"abc \\  \n    def\" \"a\\\nb\"").collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::String("abc def".to_string()),
+                Token::String("ab".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_escape_parses_error() {
+        let tokens: Vec<Token> = tokenize(r#""a\qb""#).collect();
+
+        assert_eq!(
+            tokens,
+            vec![Token::LexerError(
+                "Invalid escape sequence: \\q (line 1, column 5)".to_string()
+            )]
+        );
+
+        let tokens: Vec<Token> = tokenize(r#""\x41""#).collect();
+
+        assert_eq!(
+            tokens,
+            vec![Token::LexerError(
+                "Invalid hex escape: \\x41 (line 1, column 7)".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn vector_parses_correctly() {
+        let tokens: Vec<Token> = tokenize("#(1 #(a))").collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::VectorStart,
+                Token::Number(Object::Integer(1)),
+                Token::VectorStart,
+                Token::Symbol("a".to_string()),
+                Token::RParen,
+                Token::RParen,
+            ]
+        );
+    }
+
+    #[test]
+    fn bytevector_parses_correctly() {
+        let tokens: Vec<Token> = tokenize("#u8(1 255)").collect();
+
+        assert_eq!(
+            tokens,
+            vec![
+                Token::BytevectorStart,
+                Token::Number(Object::Integer(1)),
+                Token::Number(Object::Integer(255)),
+                Token::RParen,
+            ]
+        );
+
+        let tokens: Vec<Token> = tokenize("#u8 (1)").collect();
+
+        assert_eq!(
+            tokens,
+            vec![Token::LexerError(
+                "Invalid # syntax: #u8 (line 1, column 4)".to_string()
+            )]
+        );
+    }
 }
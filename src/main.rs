@@ -1,23 +1,123 @@
+mod builtins;
 mod env;
 mod eval;
 mod lexer;
+mod number;
 mod object;
 mod parser;
+mod symbol;
 
 use linefeed::{Interface, ReadResult};
 
 use std::cell::RefCell;
+use std::io::Read;
+use std::process::ExitCode;
 use std::rc::Rc;
 
+use builtins::set_command_line;
 use env::Env;
 use eval::eval;
+use object::Object;
 
 const PROMPT: &str = "r-scheme> ";
 
-fn main() -> std::io::Result<()> {
+const USAGE: &str = "Usage: r-scheme [FILE [ARGS...] | -e EXPR | -]
+
+With no arguments, starts an interactive session.
+  FILE        evaluate the content of FILE, passing ARGS to the program
+  -e EXPR     evaluate EXPR and print its value
+  -           evaluate the program read from the standard input";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    match args.first().map(String::as_str) {
+        None => match repl() {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(err) => {
+                eprintln!("{}", err);
+                ExitCode::FAILURE
+            }
+        },
+        Some("-h") | Some("--help") => {
+            println!("{}", USAGE);
+            ExitCode::SUCCESS
+        }
+        Some("-e") => match args.get(1) {
+            Some(expr) => {
+                let program_name = std::env::args().next().unwrap_or_default();
+                set_command_line(expression_command_line(program_name, &args[2..]));
+                run(expr, true)
+            }
+            None => {
+                eprintln!("{}", USAGE);
+                ExitCode::FAILURE
+            }
+        },
+        Some("-") => {
+            let mut program = String::new();
+            if let Err(err) = std::io::stdin().read_to_string(&mut program) {
+                eprintln!("Unable to read standard input: {}", err);
+                return ExitCode::FAILURE;
+            }
+
+            set_command_line(args.clone());
+            run(&program, false)
+        }
+        Some(path) => match std::fs::read_to_string(path) {
+            Ok(program) => {
+                set_command_line(args.clone());
+                run(&program, false)
+            }
+            Err(err) => {
+                eprintln!("Unable to read {}: {}", path, err);
+                ExitCode::FAILURE
+            }
+        },
+    }
+}
+
+/// The command line of an expression given with `-e`: as it has no script name, the interpreter's
+/// name comes first, and neither the flag nor the expression itself are arguments of the program
+fn expression_command_line(program_name: String, args: &[String]) -> Vec<String> {
+    let mut command_line = vec![program_name];
+    command_line.extend_from_slice(args);
+    command_line
+}
+
+/// Evaluates a whole program non-interactively, failing on the first uncaught error
+fn run(program: &str, print_result: bool) -> ExitCode {
+    let mut env = Rc::new(RefCell::new(Env::new()));
+
+    match eval(program, &mut env) {
+        Ok(v) => {
+            if print_result {
+                print_values(&v);
+            }
+            ExitCode::SUCCESS
+        }
+        // empty error is just no input
+        Err(err) if err.is_empty() => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Prints each value of a result on its own line, nothing being printed for an unspecified value
+fn print_values(result: &Object) {
+    for value in result.values_to_vec() {
+        if value != Object::Unspecified {
+            println!("{}", value);
+        }
+    }
+}
+
+fn repl() -> std::io::Result<()> {
     let reader = Interface::new("r-scheme")?;
 
-    reader.set_prompt(format!("{}", PROMPT).as_ref())?;
+    reader.set_prompt(PROMPT)?;
 
     println!("Welcome to r-scheme — Scheme r7 (incomplete)");
     println!(
@@ -34,7 +134,7 @@ fn main() -> std::io::Result<()> {
 
         let result = eval(input.as_ref(), &mut env);
         match result {
-            Ok(v) => println!("{}", v),
+            Ok(v) => print_values(&v),
             Err(err) => {
                 if !err.is_empty() {
                     // empty error is just no input
@@ -49,3 +149,16 @@ fn main() -> std::io::Result<()> {
     println!("Goodbye");
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expression_command_line_starts_with_the_program_name() {
+        assert_eq!(
+            expression_command_line("r-scheme".to_string(), &["a".to_string()]),
+            vec!["r-scheme".to_string(), "a".to_string()]
+        );
+    }
+}
@@ -0,0 +1,929 @@
+use std::cmp::Ordering;
+use std::rc::Rc;
+
+use num_bigint::BigInt;
+use num_complex::Complex64;
+use num_rational::BigRational;
+use num_traits::{FromPrimitive, Signed, ToPrimitive, Zero};
+
+use crate::object::Object;
+
+/// Two numbers converted to the representation in which an operation between them is carried.
+/// Following R7RS contagion rules, the result is exact only if both numbers are exact
+enum Coerced {
+    Fixnum(i64, i64),
+    Integer(BigInt, BigInt),
+    Rational(BigRational, BigRational),
+    Real(f64, f64),
+    Complex(Complex64, Complex64),
+}
+
+/// Position of a number in the tower, None for non-numeric values
+fn rank(obj: &Object) -> Option<u8> {
+    match obj {
+        Object::Integer(_) => Some(0),
+        Object::BigInteger(_) => Some(1),
+        Object::Rational(_) => Some(2),
+        Object::Float(_) => Some(3),
+        Object::Complex(_) => Some(4),
+        _ => None,
+    }
+}
+
+fn coerce(operator: &str, left: &Object, right: &Object) -> Result<Coerced, String> {
+    let rank = match (rank(left), rank(right)) {
+        (Some(l), Some(r)) => l.max(r),
+        _ => {
+            return Err(format!(
+                "Unable to apply binary operation on non-numeric values: ({} {} {})",
+                operator, left, right
+            ))
+        }
+    };
+
+    // the conversions cannot fail, as both values are below the common rank
+    let coerced = match (left, right) {
+        (Object::Integer(l), Object::Integer(r)) => Coerced::Fixnum(*l, *r),
+        _ if rank == 1 => Coerced::Integer(to_bigint(left).unwrap(), to_bigint(right).unwrap()),
+        _ if rank == 2 => {
+            Coerced::Rational(to_rational(left).unwrap(), to_rational(right).unwrap())
+        }
+        _ if rank == 3 => Coerced::Real(to_f64(left).unwrap(), to_f64(right).unwrap()),
+        _ => Coerced::Complex(to_complex(left).unwrap(), to_complex(right).unwrap()),
+    };
+
+    Ok(coerced)
+}
+
+pub fn is_number(obj: &Object) -> bool {
+    rank(obj).is_some()
+}
+
+pub fn is_real(obj: &Object) -> bool {
+    is_number(obj) && !matches!(obj, Object::Complex(_))
+}
+
+/// Returns None for non-numeric values
+pub fn is_exact(obj: &Object) -> Option<bool> {
+    match obj {
+        Object::Integer(_) | Object::BigInteger(_) | Object::Rational(_) => Some(true),
+        Object::Float(_) | Object::Complex(_) => Some(false),
+        _ => None,
+    }
+}
+
+pub fn is_integer(obj: &Object) -> bool {
+    match obj {
+        Object::Integer(_) | Object::BigInteger(_) => true,
+        Object::Float(f) => f.is_finite() && f.fract() == 0.0,
+        _ => false,
+    }
+}
+
+/// Builds an exact integer, using the smallest representation it fits in
+pub fn from_bigint(n: BigInt) -> Object {
+    match n.to_i64() {
+        Some(n) => Object::Integer(n),
+        None => Object::BigInteger(Rc::new(n)),
+    }
+}
+
+/// Builds an exact number, which is an integer if the denominator is 1
+pub fn from_rational(q: BigRational) -> Object {
+    if q.is_integer() {
+        from_bigint(q.to_integer())
+    } else {
+        Object::Rational(Rc::new(q))
+    }
+}
+
+/// Builds an inexact complex number, which is a real if its imaginary part is zero
+pub fn from_complex(z: Complex64) -> Object {
+    if z.im == 0.0 {
+        Object::Float(z.re)
+    } else {
+        Object::Complex(z)
+    }
+}
+
+pub fn to_bigint(obj: &Object) -> Option<BigInt> {
+    match obj {
+        Object::Integer(n) => Some(BigInt::from(*n)),
+        Object::BigInteger(n) => Some(n.as_ref().clone()),
+        _ => None,
+    }
+}
+
+pub fn to_rational(obj: &Object) -> Option<BigRational> {
+    match obj {
+        Object::Rational(q) => Some(q.as_ref().clone()),
+        _ => to_bigint(obj).map(BigRational::from_integer),
+    }
+}
+
+pub fn to_f64(obj: &Object) -> Option<f64> {
+    match obj {
+        Object::Integer(n) => Some(*n as f64),
+        Object::BigInteger(n) => n.to_f64(),
+        Object::Rational(q) => q.to_f64(),
+        Object::Float(f) => Some(*f),
+        _ => None,
+    }
+}
+
+pub fn to_complex(obj: &Object) -> Option<Complex64> {
+    match obj {
+        Object::Complex(z) => Some(*z),
+        _ => to_f64(obj).map(|re| Complex64::new(re, 0.0)),
+    }
+}
+
+/// The exact value of a real number, None for infinities, NaN and complex numbers
+fn to_exact_rational(obj: &Object) -> Option<BigRational> {
+    match obj {
+        Object::Float(f) => BigRational::from_f64(*f),
+        _ => to_rational(obj),
+    }
+}
+
+pub fn to_exact(obj: &Object) -> Result<Object, String> {
+    match obj {
+        Object::Float(f) => match BigRational::from_f64(*f) {
+            Some(q) => Ok(from_rational(q)),
+            None => Err(format!("No exact representation for {}", obj)),
+        },
+        Object::Complex(z) if z.im == 0.0 => to_exact(&Object::Float(z.re)),
+        // exact numbers are all real
+        Object::Complex(_) => Err(format!("No exact representation for {}", obj)),
+        _ if is_number(obj) => Ok(obj.clone()),
+        _ => Err(format!("Expected a number, but was given {}", obj)),
+    }
+}
+
+pub fn to_inexact(obj: &Object) -> Result<Object, String> {
+    match (obj, to_f64(obj)) {
+        (Object::Complex(_), _) => Ok(obj.clone()),
+        (_, Some(f)) => Ok(Object::Float(f)),
+        _ => Err(format!("Expected a number, but was given {}", obj)),
+    }
+}
+
+pub fn add(left: &Object, right: &Object) -> Result<Object, String> {
+    Ok(match coerce("+", left, right)? {
+        Coerced::Fixnum(l, r) => match l.checked_add(r) {
+            Some(n) => Object::Integer(n),
+            None => from_bigint(BigInt::from(l) + r),
+        },
+        Coerced::Integer(l, r) => from_bigint(l + r),
+        Coerced::Rational(l, r) => from_rational(l + r),
+        Coerced::Real(l, r) => Object::Float(l + r),
+        Coerced::Complex(l, r) => from_complex(l + r),
+    })
+}
+
+pub fn sub(left: &Object, right: &Object) -> Result<Object, String> {
+    Ok(match coerce("-", left, right)? {
+        Coerced::Fixnum(l, r) => match l.checked_sub(r) {
+            Some(n) => Object::Integer(n),
+            None => from_bigint(BigInt::from(l) - r),
+        },
+        Coerced::Integer(l, r) => from_bigint(l - r),
+        Coerced::Rational(l, r) => from_rational(l - r),
+        Coerced::Real(l, r) => Object::Float(l - r),
+        Coerced::Complex(l, r) => from_complex(l - r),
+    })
+}
+
+/// Negates a number, keeping the sign of inexact zeros and NaNs
+pub fn neg(obj: &Object) -> Result<Object, String> {
+    match obj {
+        Object::Float(f) => Ok(Object::Float(-f)),
+        Object::Complex(z) => Ok(Object::Complex(-z)),
+        _ => sub(&Object::Integer(0), obj),
+    }
+}
+
+pub fn mul(left: &Object, right: &Object) -> Result<Object, String> {
+    Ok(match coerce("*", left, right)? {
+        Coerced::Fixnum(l, r) => match l.checked_mul(r) {
+            Some(n) => Object::Integer(n),
+            None => from_bigint(BigInt::from(l) * r),
+        },
+        Coerced::Integer(l, r) => from_bigint(l * r),
+        Coerced::Rational(l, r) => from_rational(l * r),
+        Coerced::Real(l, r) => Object::Float(l * r),
+        Coerced::Complex(l, r) => from_complex(l * r),
+    })
+}
+
+pub fn div(left: &Object, right: &Object) -> Result<Object, String> {
+    let division_by_zero = || format!("Division by zero: (/ {} {})", left, right);
+
+    Ok(match coerce("/", left, right)? {
+        Coerced::Fixnum(_, 0) => return Err(division_by_zero()),
+        Coerced::Fixnum(l, r) => match (l.checked_rem(r), l.checked_div(r)) {
+            (Some(0), Some(n)) => Object::Integer(n),
+            _ => from_rational(BigRational::new(BigInt::from(l), BigInt::from(r))),
+        },
+        Coerced::Integer(l, r) => {
+            if r.is_zero() {
+                return Err(division_by_zero());
+            }
+            from_rational(BigRational::new(l, r))
+        }
+        Coerced::Rational(l, r) => {
+            if r.is_zero() {
+                return Err(division_by_zero());
+            }
+            from_rational(l / r)
+        }
+        Coerced::Real(l, r) => Object::Float(l / r),
+        Coerced::Complex(l, r) => from_complex(l / r),
+    })
+}
+
+/// Orders two numbers, None meaning they cannot be ordered (NaN).
+/// Complex numbers can only be compared for equality, by the `=` and `!=` operators
+pub fn compare(operator: &str, left: &Object, right: &Object) -> Result<Option<Ordering>, String> {
+    // an exact number is compared to a finite float through the float's exact value,
+    // as converting the exact number to a float could round it
+    let mixed = matches!(
+        (is_exact(left), is_exact(right)),
+        (Some(true), Some(false)) | (Some(false), Some(true))
+    );
+    if mixed {
+        if let (Some(l), Some(r)) = (to_exact_rational(left), to_exact_rational(right)) {
+            return Ok(Some(l.cmp(&r)));
+        }
+    }
+
+    Ok(match coerce(operator, left, right)? {
+        Coerced::Fixnum(l, r) => Some(l.cmp(&r)),
+        Coerced::Integer(l, r) => Some(l.cmp(&r)),
+        Coerced::Rational(l, r) => Some(l.cmp(&r)),
+        Coerced::Real(l, r) => l.partial_cmp(&r),
+        Coerced::Complex(l, r) if operator == "=" || operator == "!=" => {
+            Some(Ordering::Equal).filter(|_| l == r)
+        }
+        Coerced::Complex(_, _) => {
+            return Err(format!(
+                "Unable to order complex numbers: ({} {} {})",
+                operator, left, right
+            ))
+        }
+    })
+}
+
+pub fn numerator(obj: &Object) -> Result<Object, String> {
+    match obj {
+        Object::Rational(q) => Ok(from_bigint(q.numer().clone())),
+        Object::Float(_) => to_inexact(&numerator(&to_exact(obj)?)?),
+        _ if is_real(obj) => Ok(obj.clone()),
+        _ => Err(format!("Expected a real number, but was given {}", obj)),
+    }
+}
+
+pub fn denominator(obj: &Object) -> Result<Object, String> {
+    match obj {
+        Object::Rational(q) => Ok(from_bigint(q.denom().clone())),
+        Object::Float(_) => to_inexact(&denominator(&to_exact(obj)?)?),
+        _ if is_real(obj) => Ok(Object::Integer(1)),
+        _ => Err(format!("Expected a real number, but was given {}", obj)),
+    }
+}
+
+/// Builds `re + im * i`, which stays exact if `im` is an exact zero
+pub fn make_rectangular(re: &Object, im: &Object) -> Result<Object, String> {
+    match (to_f64(re), to_f64(im)) {
+        (Some(_), Some(_)) if im == &Object::Integer(0) => Ok(re.clone()),
+        (Some(re), Some(im)) => Ok(from_complex(Complex64::new(re, im))),
+        _ => Err(format!(
+            "Expected real numbers, but was given {} and {}",
+            re, im
+        )),
+    }
+}
+
+/// Builds `magnitude * e^(angle * i)`, which stays exact if `angle` is an exact zero
+pub fn make_polar(magnitude: &Object, angle: &Object) -> Result<Object, String> {
+    match (to_f64(magnitude), to_f64(angle)) {
+        (Some(_), Some(_)) if angle == &Object::Integer(0) => Ok(magnitude.clone()),
+        (Some(r), Some(theta)) => Ok(from_complex(Complex64::from_polar(r, theta))),
+        _ => Err(format!(
+            "Expected real numbers, but was given {} and {}",
+            magnitude, angle
+        )),
+    }
+}
+
+pub fn real_part(obj: &Object) -> Result<Object, String> {
+    match obj {
+        Object::Complex(z) => Ok(Object::Float(z.re)),
+        _ if is_number(obj) => Ok(obj.clone()),
+        _ => Err(format!("Expected a number, but was given {}", obj)),
+    }
+}
+
+pub fn imag_part(obj: &Object) -> Result<Object, String> {
+    match obj {
+        Object::Complex(z) => Ok(Object::Float(z.im)),
+        _ if is_number(obj) => Ok(Object::Integer(0)),
+        _ => Err(format!("Expected a number, but was given {}", obj)),
+    }
+}
+
+pub fn magnitude(obj: &Object) -> Result<Object, String> {
+    match obj {
+        Object::Complex(z) => Ok(Object::Float(z.norm())),
+        _ => match compare("magnitude", obj, &Object::Integer(0))? {
+            Some(Ordering::Less) => sub(&Object::Integer(0), obj),
+            _ => Ok(obj.clone()),
+        },
+    }
+}
+
+pub fn angle(obj: &Object) -> Result<Object, String> {
+    match obj {
+        Object::Complex(z) => Ok(Object::Float(z.arg())),
+        Object::Float(f) => Ok(Object::Float(Complex64::new(*f, 0.0).arg())),
+        _ => match compare("angle", obj, &Object::Integer(0))? {
+            Some(Ordering::Less) => Ok(Object::Float(std::f64::consts::PI)),
+            _ => Ok(Object::Integer(0)),
+        },
+    }
+}
+
+/// Exact square root of a non-negative exact integer, if it has one
+fn exact_integer_sqrt(n: &BigInt) -> Option<BigInt> {
+    let root = n.sqrt();
+    Some(root).filter(|root| root * root == *n)
+}
+
+/// The principal square root, which is exact if `obj` is the square of an exact number,
+/// and complex for negative numbers
+pub fn sqrt(obj: &Object) -> Result<Object, String> {
+    if let Some(q) = to_rational(obj) {
+        if !q.is_negative() {
+            let root = exact_integer_sqrt(q.numer())
+                .zip(exact_integer_sqrt(q.denom()))
+                .map(|(n, d)| from_rational(BigRational::new(n, d)));
+
+            if let Some(root) = root {
+                return Ok(root);
+            }
+        }
+    }
+
+    match obj {
+        Object::Complex(z) => Ok(from_complex(z.sqrt())),
+        _ => match to_f64(obj) {
+            Some(f) if f < 0.0 => Ok(from_complex(Complex64::new(0.0, (-f).sqrt()))),
+            Some(f) => Ok(Object::Float(f.sqrt())),
+            None => Err(format!("Expected a number, but was given {}", obj)),
+        },
+    }
+}
+
+/// Writes a number in the given radix, following the R7RS external representation.
+/// Inexact numbers can only be written in radix 10
+pub fn to_string(obj: &Object, radix: u32) -> Result<String, String> {
+    match obj {
+        Object::Integer(n) if radix == 10 => Ok(n.to_string()),
+        Object::Integer(n) => Ok(BigInt::from(*n).to_str_radix(radix)),
+        Object::BigInteger(n) => Ok(n.to_str_radix(radix)),
+        Object::Rational(q) => Ok(format!(
+            "{}/{}",
+            q.numer().to_str_radix(radix),
+            q.denom().to_str_radix(radix)
+        )),
+        Object::Float(_) | Object::Complex(_) if radix != 10 => Err(format!(
+            "Inexact numbers can only be written in radix 10: {}",
+            obj
+        )),
+        Object::Float(f) => Ok(float_to_string(*f)),
+        Object::Complex(z) => {
+            let im = match float_to_string(z.im) {
+                // +inf.0, -inf.0 and +nan.0 are always signed
+                im if im.starts_with(['+', '-']) => im,
+                im => format!("+{}", im),
+            };
+
+            if z.re == 0.0 && z.re.is_sign_positive() {
+                Ok(format!("{}i", im))
+            } else {
+                Ok(format!("{}{}i", float_to_string(z.re), im))
+            }
+        }
+        _ => Err(format!("Expected a number, but was given {}", obj)),
+    }
+}
+
+/// The shortest representation reading back as the same float, always marked as inexact
+fn float_to_string(f: f64) -> String {
+    if f.is_nan() {
+        "+nan.0".to_string()
+    } else if f.is_infinite() {
+        if f > 0.0 { "+inf.0" } else { "-inf.0" }.to_string()
+    } else {
+        // unlike Display, Debug keeps the decimal point and uses exponents for large magnitudes
+        format!("{:?}", f)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Exactness {
+    Exact,
+    Inexact,
+}
+
+/// Reads a number with the R7RS syntax, returning None if `s` is not one.
+/// `radix` is used unless `s` has a radix prefix
+pub fn parse_number(s: &str, radix: u32) -> Option<Object> {
+    let mut s = s;
+    let mut radix_prefix = None;
+    let mut exactness = None;
+
+    // prefixes may come in any order, but at most once each
+    while let Some(rest) = s.strip_prefix('#') {
+        let mut chars = rest.chars();
+
+        match chars.next()?.to_ascii_lowercase() {
+            'x' if radix_prefix.is_none() => radix_prefix = Some(16),
+            'd' if radix_prefix.is_none() => radix_prefix = Some(10),
+            'o' if radix_prefix.is_none() => radix_prefix = Some(8),
+            'b' if radix_prefix.is_none() => radix_prefix = Some(2),
+            'e' if exactness.is_none() => exactness = Some(Exactness::Exact),
+            'i' if exactness.is_none() => exactness = Some(Exactness::Inexact),
+            _ => return None,
+        }
+
+        s = chars.as_str();
+    }
+
+    let radix = radix_prefix.unwrap_or(radix);
+    let number = parse_complex(s, radix, exactness)?;
+
+    // there are only inexact complex numbers
+    match (number, exactness) {
+        (Object::Complex(_), Some(Exactness::Exact)) => None,
+        (number, _) => Some(number),
+    }
+}
+
+fn parse_complex(s: &str, radix: u32, exactness: Option<Exactness>) -> Option<Object> {
+    if let Some(real) = parse_real(s, radix, exactness) {
+        return Some(real);
+    }
+
+    // polar notation: magnitude@angle
+    if let Some((magnitude, angle)) = s.split_once('@') {
+        return make_polar(
+            &parse_real(magnitude, radix, exactness)?,
+            &parse_real(angle, radix, exactness)?,
+        )
+        .ok();
+    }
+
+    // rectangular notation: [real](+|-)[imaginary]i
+    let body = s.strip_suffix(['i', 'I'])?;
+    let sign = body
+        .char_indices()
+        .rev()
+        .find(|&(i, c)| {
+            // the sign of an exponent is not the start of the imaginary part
+            (c == '+' || c == '-') && (i == 0 || radix != 10 || !body[..i].ends_with(['e', 'E']))
+        })
+        .map(|(i, _)| i)?;
+
+    let re = match &body[..sign] {
+        "" => Object::Integer(0),
+        re => parse_real(re, radix, exactness)?,
+    };
+    let im = match &body[sign..] {
+        "+" => Object::Integer(1),
+        "-" => Object::Integer(-1),
+        im => parse_real(im, radix, exactness)?,
+    };
+
+    make_rectangular(&re, &im).ok()
+}
+
+/// Reads a signed integer, rational, decimal or special float, returning None if `s` is not one
+fn parse_real(s: &str, radix: u32, exactness: Option<Exactness>) -> Option<Object> {
+    let special = match s.to_ascii_lowercase().as_str() {
+        "+inf.0" => Some(f64::INFINITY),
+        "-inf.0" => Some(f64::NEG_INFINITY),
+        "+nan.0" | "-nan.0" => Some(f64::NAN),
+        _ => None,
+    };
+    if let Some(f) = special {
+        // infinities and NaN have no exact representation
+        return match exactness {
+            Some(Exactness::Exact) => None,
+            _ => Some(Object::Float(f)),
+        };
+    }
+
+    let (negative, unsigned) = match s.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+
+    let exact = if let Some((n, d)) = unsigned.split_once('/') {
+        let d = parse_uinteger(d, radix)?;
+        if d.is_zero() {
+            return None;
+        }
+        BigRational::new(parse_uinteger(n, radix)?, d)
+    } else if radix == 10 && unsigned.contains(['.', 'e', 'E']) {
+        if exactness != Some(Exactness::Exact) {
+            split_decimal(unsigned)?;
+
+            // the standard library rounds correctly, and overflows to infinity or zero
+            // instead of computing a huge exact value
+            let f: f64 = unsigned.parse().ok()?;
+            return Some(Object::Float(if negative { -f } else { f }));
+        }
+        parse_decimal(unsigned)?
+    } else {
+        BigRational::from_integer(parse_uinteger(unsigned, radix)?)
+    };
+
+    let exact = if negative { -exact } else { exact };
+
+    match exactness {
+        Some(Exactness::Inexact) => exact.to_f64().map(Object::Float),
+        _ => Some(from_rational(exact)),
+    }
+}
+
+/// Reads a non-empty sequence of digits, without sign
+fn parse_uinteger(s: &str, radix: u32) -> Option<BigInt> {
+    if s.is_empty() || !s.chars().all(|c| c.is_digit(radix)) {
+        return None;
+    }
+
+    BigInt::parse_bytes(s.as_bytes(), radix)
+}
+
+/// The largest exponent of an exact decimal, beyond which computing its value would take too long
+const MAX_EXACT_EXPONENT: u32 = 10_000;
+
+/// Splits an unsigned decimal, such as `1.5`, `.5`, `1.` or `15e-1`, into its integer digits,
+/// fraction digits and signed exponent, returning None if `s` is not one
+fn split_decimal(s: &str) -> Option<(&str, &str, &str)> {
+    let (mantissa, exponent) = match s.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => {
+            let digits = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+            if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit()) {
+                return None;
+            }
+            (mantissa, exponent)
+        }
+        None => (s, "0"),
+    };
+
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let is_digits = |part: &str| part.chars().all(|c| c.is_ascii_digit());
+    if integer.is_empty() && fraction.is_empty() || !is_digits(integer) || !is_digits(fraction) {
+        return None;
+    }
+
+    Some((integer, fraction, exponent))
+}
+
+/// Reads the exact value of an unsigned decimal, returning None if its exponent is too large
+fn parse_decimal(s: &str) -> Option<BigRational> {
+    let (integer, fraction, exponent) = split_decimal(s)?;
+    let exponent = exponent
+        .parse::<i32>()
+        .ok()
+        .filter(|e| e.unsigned_abs() <= MAX_EXACT_EXPONENT)?;
+
+    let value = BigRational::from_integer(format!("{}{}", integer, fraction).parse().ok()?);
+    let scale = exponent.checked_sub(i32::try_from(fraction.len()).ok()?)?;
+    let power = BigRational::from_integer(BigInt::from(10).pow(scale.unsigned_abs()));
+
+    Some(if scale < 0 {
+        value / power
+    } else {
+        value * power
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn big(s: &str) -> Object {
+        from_bigint(s.parse().unwrap())
+    }
+
+    fn ratio(n: i64, d: i64) -> Object {
+        from_rational(BigRational::new(n.into(), d.into()))
+    }
+
+    #[test]
+    fn overflow_promotes_to_bignum() {
+        assert_eq!(
+            add(&Object::Integer(i64::MAX), &Object::Integer(1)),
+            Ok(big("9223372036854775808"))
+        );
+        assert_eq!(
+            sub(&big("9223372036854775808"), &Object::Integer(1)),
+            Ok(Object::Integer(i64::MAX))
+        );
+    }
+
+    #[test]
+    fn exact_division() {
+        assert_eq!(
+            div(&Object::Integer(6), &Object::Integer(4)),
+            Ok(ratio(3, 2))
+        );
+        assert_eq!(div(&ratio(1, 2), &ratio(1, 4)), Ok(Object::Integer(2)));
+        assert!(div(&ratio(1, 2), &Object::Integer(0)).is_err());
+    }
+
+    #[test]
+    fn contagion() {
+        assert_eq!(add(&ratio(1, 2), &Object::Integer(1)), Ok(ratio(3, 2)));
+        assert_eq!(
+            add(&ratio(1, 2), &Object::Float(0.25)),
+            Ok(Object::Float(0.75))
+        );
+        assert_eq!(
+            mul(&big("100000000000000000000"), &Object::Float(0.5)),
+            Ok(Object::Float(5e19))
+        );
+    }
+
+    #[test]
+    fn comparisons_across_representations() {
+        assert_eq!(
+            compare("<", &ratio(1, 3), &Object::Float(0.34)),
+            Ok(Some(Ordering::Less))
+        );
+        assert_eq!(
+            compare("=", &big("100000000000000000000"), &Object::Integer(1)),
+            Ok(Some(Ordering::Greater))
+        );
+        assert_eq!(
+            compare("=", &Object::Float(f64::NAN), &Object::Integer(1)),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn exact_comparisons_with_floats_keep_precision() {
+        // 2^53 + 1 has no float representation, it would be rounded to 2^53
+        let odd = Object::Integer(9007199254740993);
+        let float = Object::Float(9007199254740992.0);
+
+        assert_eq!(compare("=", &odd, &float), Ok(Some(Ordering::Greater)));
+        assert_eq!(compare("<", &float, &odd), Ok(Some(Ordering::Less)));
+        assert_eq!(
+            compare("=", &Object::Integer(9007199254740992), &float),
+            Ok(Some(Ordering::Equal))
+        );
+        assert_eq!(
+            compare("<", &odd, &Object::Float(f64::INFINITY)),
+            Ok(Some(Ordering::Less))
+        );
+    }
+
+    #[test]
+    fn exactness_conversions() {
+        assert_eq!(to_exact(&Object::Float(0.25)), Ok(ratio(1, 4)));
+        assert_eq!(to_exact(&Object::Float(3.0)), Ok(Object::Integer(3)));
+        assert!(to_exact(&Object::Float(f64::INFINITY)).is_err());
+        assert_eq!(
+            to_exact(&Object::Complex(Complex64::new(1.0, 2.0))),
+            Err("No exact representation for 1.0+2.0i".to_string())
+        );
+        assert_eq!(
+            to_exact(&Object::Complex(Complex64::new(0.5, 0.0))),
+            Ok(ratio(1, 2))
+        );
+        assert_eq!(to_inexact(&ratio(1, 4)), Ok(Object::Float(0.25)));
+    }
+
+    #[test]
+    fn numerator_and_denominator() {
+        assert_eq!(numerator(&ratio(6, 4)), Ok(Object::Integer(3)));
+        assert_eq!(denominator(&ratio(6, 4)), Ok(Object::Integer(2)));
+        assert_eq!(denominator(&Object::Integer(5)), Ok(Object::Integer(1)));
+        assert_eq!(denominator(&Object::Float(0.75)), Ok(Object::Float(4.0)));
+    }
+
+    #[test]
+    fn parse_big_integers() {
+        assert_eq!(parse_number("42", 10), Some(Object::Integer(42)));
+        assert_eq!(
+            parse_number("-123456789012345678901234567890", 10),
+            Some(big("-123456789012345678901234567890"))
+        );
+        assert_eq!(parse_number("1_000", 10), None);
+    }
+
+    fn complex(re: f64, im: f64) -> Object {
+        Object::Complex(Complex64::new(re, im))
+    }
+
+    #[test]
+    fn complex_arithmetic() {
+        assert_eq!(
+            add(&complex(1.0, 2.0), &Object::Integer(1)),
+            Ok(complex(2.0, 2.0))
+        );
+        assert_eq!(
+            mul(&complex(0.0, 1.0), &complex(0.0, 1.0)),
+            Ok(Object::Float(-1.0))
+        );
+        assert_eq!(
+            compare("=", &complex(1.0, 2.0), &complex(1.0, 2.0)),
+            Ok(Some(Ordering::Equal))
+        );
+        assert!(compare("<", &complex(1.0, 2.0), &Object::Integer(1)).is_err());
+    }
+
+    #[test]
+    fn square_roots() {
+        assert_eq!(sqrt(&Object::Integer(16)), Ok(Object::Integer(4)));
+        assert_eq!(sqrt(&ratio(9, 4)), Ok(ratio(3, 2)));
+        assert_eq!(sqrt(&Object::Integer(2)), Ok(Object::Float(2f64.sqrt())));
+        assert_eq!(sqrt(&Object::Integer(-4)), Ok(complex(0.0, 2.0)));
+        assert_eq!(sqrt(&Object::Float(-2.25)), Ok(complex(0.0, 1.5)));
+    }
+
+    #[test]
+    fn complex_parts() {
+        assert_eq!(magnitude(&complex(3.0, 4.0)), Ok(Object::Float(5.0)));
+        assert_eq!(magnitude(&Object::Integer(-5)), Ok(Object::Integer(5)));
+        assert_eq!(real_part(&complex(3.0, 4.0)), Ok(Object::Float(3.0)));
+        assert_eq!(imag_part(&complex(3.0, 4.0)), Ok(Object::Float(4.0)));
+        assert_eq!(imag_part(&Object::Integer(3)), Ok(Object::Integer(0)));
+        assert_eq!(
+            angle(&Object::Integer(-1)),
+            Ok(Object::Float(std::f64::consts::PI))
+        );
+    }
+
+    #[test]
+    fn parse_complex_numbers() {
+        assert_eq!(parse_number("3+4i", 10), Some(complex(3.0, 4.0)));
+        assert_eq!(parse_number("-1.5-2i", 10), Some(complex(-1.5, -2.0)));
+        assert_eq!(parse_number("+i", 10), Some(complex(0.0, 1.0)));
+        assert_eq!(parse_number("-2i", 10), Some(complex(0.0, -2.0)));
+        assert_eq!(parse_number("1e2+1e-1i", 10), Some(complex(100.0, 0.1)));
+        assert_eq!(parse_number("3+0i", 10), Some(Object::Integer(3)));
+        assert_eq!(parse_number("2@0", 10), Some(Object::Integer(2)));
+        assert_eq!(
+            parse_number("1@1.57", 10),
+            Some(from_complex(Complex64::from_polar(1.0, 1.57)))
+        );
+        assert_eq!(parse_number("hi", 10), None);
+        assert_eq!(parse_number("-pi", 10), None);
+        assert_eq!(parse_number("a+bi", 10), None);
+    }
+
+    #[test]
+    fn parse_radix_prefixes() {
+        assert_eq!(parse_number("#xff", 10), Some(Object::Integer(255)));
+        assert_eq!(parse_number("#X-1A", 10), Some(Object::Integer(-26)));
+        assert_eq!(parse_number("#b101", 10), Some(Object::Integer(5)));
+        assert_eq!(parse_number("#o17", 10), Some(Object::Integer(15)));
+        assert_eq!(parse_number("#d19", 10), Some(Object::Integer(19)));
+        assert_eq!(parse_number("ff", 16), Some(Object::Integer(255)));
+        assert_eq!(parse_number("#b102", 10), None);
+        assert_eq!(parse_number("#x#x1", 10), None);
+        assert_eq!(parse_number("#x1.5", 10), None);
+    }
+
+    #[test]
+    fn parse_exactness_prefixes() {
+        assert_eq!(parse_number("#e1.5", 10), Some(ratio(3, 2)));
+        assert_eq!(parse_number("#e0.1", 10), Some(ratio(1, 10)));
+        assert_eq!(parse_number("#e1e3", 10), Some(Object::Integer(1000)));
+        assert_eq!(parse_number("#i3/4", 10), Some(Object::Float(0.75)));
+        assert_eq!(parse_number("#x#i10", 10), Some(Object::Float(16.0)));
+        assert_eq!(parse_number("#i#x10", 10), Some(Object::Float(16.0)));
+        assert_eq!(parse_number("#e+inf.0", 10), None);
+        assert_eq!(parse_number("#e#i1", 10), None);
+    }
+
+    #[test]
+    fn parse_rationals() {
+        assert_eq!(parse_number("1/3", 10), Some(ratio(1, 3)));
+        assert_eq!(parse_number("-6/4", 10), Some(ratio(-3, 2)));
+        assert_eq!(parse_number("4/2", 10), Some(Object::Integer(2)));
+        assert_eq!(parse_number("#x1/a", 10), Some(ratio(1, 10)));
+        assert_eq!(parse_number("1/0", 10), None);
+        assert_eq!(parse_number("1/", 10), None);
+        assert_eq!(parse_number("1/-2", 10), None);
+        assert_eq!(parse_number("1.5/2", 10), None);
+    }
+
+    #[test]
+    fn parse_decimals() {
+        assert_eq!(parse_number("1.5", 10), Some(Object::Float(1.5)));
+        assert_eq!(parse_number(".5", 10), Some(Object::Float(0.5)));
+        assert_eq!(parse_number("-1.", 10), Some(Object::Float(-1.0)));
+        assert_eq!(parse_number("1e3", 10), Some(Object::Float(1000.0)));
+        assert_eq!(parse_number("1.5E-1", 10), Some(Object::Float(0.15)));
+        assert_eq!(parse_number(".", 10), None);
+        assert_eq!(parse_number("1e", 10), None);
+        assert_eq!(parse_number("1.2.3", 10), None);
+    }
+
+    #[test]
+    fn parse_huge_exponents() {
+        assert_eq!(
+            parse_number("1e100000000", 10),
+            Some(Object::Float(f64::INFINITY))
+        );
+        assert_eq!(parse_number("-1e-100000000", 10), Some(Object::Float(-0.0)));
+        assert_eq!(
+            parse_number("1e99999999999999999999", 10),
+            Some(Object::Float(f64::INFINITY))
+        );
+        assert_eq!(parse_number("#e1e100000000", 10), None);
+        assert_eq!(parse_number("#e1e-100000000", 10), None);
+        assert_eq!(parse_number("#e1e3", 10), Some(Object::Integer(1000)));
+    }
+
+    #[test]
+    fn parse_special_floats() {
+        assert_eq!(
+            parse_number("+inf.0", 10),
+            Some(Object::Float(f64::INFINITY))
+        );
+        assert_eq!(
+            parse_number("-inf.0", 10),
+            Some(Object::Float(f64::NEG_INFINITY))
+        );
+        assert!(matches!(parse_number("+nan.0", 10), Some(Object::Float(f)) if f.is_nan()));
+        assert_eq!(parse_number("inf", 10), None);
+        assert_eq!(parse_number("NaN", 10), None);
+        assert_eq!(parse_number("infinity", 10), None);
+        assert_eq!(parse_number("+inf", 10), None);
+    }
+
+    #[test]
+    fn write_numbers() {
+        assert_eq!(to_string(&Object::Integer(-42), 10), Ok("-42".to_string()));
+        assert_eq!(to_string(&Object::Integer(255), 16), Ok("ff".to_string()));
+        assert_eq!(to_string(&Object::Integer(-5), 2), Ok("-101".to_string()));
+        assert_eq!(to_string(&ratio(-1, 10), 16), Ok("-1/a".to_string()));
+        assert_eq!(to_string(&Object::Float(1.0), 10), Ok("1.0".to_string()));
+        assert_eq!(to_string(&Object::Float(0.1), 10), Ok("0.1".to_string()));
+        assert_eq!(
+            to_string(&Object::Float(1e300), 10),
+            Ok("1e300".to_string())
+        );
+        assert_eq!(
+            to_string(&Object::Float(f64::NEG_INFINITY), 10),
+            Ok("-inf.0".to_string())
+        );
+        assert_eq!(
+            to_string(&Object::Float(f64::NAN), 10),
+            Ok("+nan.0".to_string())
+        );
+        assert_eq!(
+            to_string(&complex(1.0, -2.5), 10),
+            Ok("1.0-2.5i".to_string())
+        );
+        assert_eq!(to_string(&complex(0.0, 2.0), 10), Ok("+2.0i".to_string()));
+        assert_eq!(
+            to_string(&complex(1.0, f64::INFINITY), 10),
+            Ok("1.0+inf.0i".to_string())
+        );
+        assert!(to_string(&Object::Float(0.5), 2).is_err());
+    }
+
+    #[test]
+    fn written_numbers_read_back() {
+        let numbers = [
+            Object::Integer(i64::MIN),
+            big("-123456789012345678901234567890"),
+            ratio(-22, 7),
+            Object::Float(1.0),
+            Object::Float(-0.0),
+            Object::Float(0.1 + 0.2),
+            Object::Float(1e-300),
+            Object::Float(123456789.125),
+            Object::Float(f64::MAX),
+            Object::Float(f64::INFINITY),
+            complex(0.5, -1e20),
+            complex(-0.0, 1.0),
+        ];
+
+        for radix in [2, 8, 10, 16] {
+            for n in &numbers {
+                if let Ok(s) = to_string(n, radix) {
+                    assert_eq!(parse_number(&s, radix).as_ref(), Some(n), "{}", s);
+                }
+            }
+        }
+    }
+}
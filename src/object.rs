@@ -1,48 +1,533 @@
+use std::cell::RefCell;
 use std::fmt;
+use std::rc::Rc;
+
+use num_bigint::BigInt;
+use num_complex::Complex64;
+use num_rational::BigRational;
+
+use crate::env::EnvRef;
+use crate::lexer::CHAR_NAMES;
+use crate::number;
+use crate::symbol::Symbol;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Object {
     Integer(i64),
+    /// An exact integer that does not fit in an i64
+    BigInteger(Rc<BigInt>),
+    /// An exact number whose denominator is not 1
+    Rational(Rc<BigRational>),
     Float(f64),
+    /// An inexact number whose imaginary part is not 0
+    Complex(Complex64),
     Bool(bool),
-    String(String),
-    Symbol(String),
-    Lambda(Vec<String>, Vec<Object>),
-    List(Vec<Object>),
+    Char(char),
+    String(Rc<Str>),
+    Symbol(Symbol),
+    Lambda(Rc<Lambda>),
+    CaseLambda(Rc<CaseLambda>),
+    Native(&'static Native),
+    Pair(Rc<Pair>),
+    /// The multiple values returned by `values`, unless there is exactly one
+    Values(Rc<Vec<Object>>),
+    Vector(Rc<Vector>),
+    Bytevector(Rc<Bytevector>),
+    /// The empty list
+    Nil,
+    /// The value of expressions evaluated only for their side effects
+    Unspecified,
+}
+
+impl Object {
+    pub fn cons(car: Object, cdr: Object) -> Object {
+        Object::Pair(Rc::new(Pair {
+            car: RefCell::new(car),
+            cdr: RefCell::new(cdr),
+        }))
+    }
+
+    /// Builds the interned symbol named `name`
+    pub fn symbol(name: &str) -> Object {
+        Object::Symbol(Symbol::intern(name))
+    }
+
+    /// Builds a new mutable string
+    pub fn string(s: impl Into<String>) -> Object {
+        Object::String(Rc::new(Str {
+            chars: RefCell::new(s.into()),
+            mutable: true,
+        }))
+    }
+
+    /// Builds a string constant, as written in the source of a program
+    pub fn string_literal(s: impl Into<String>) -> Object {
+        Object::String(Rc::new(Str {
+            chars: RefCell::new(s.into()),
+            mutable: false,
+        }))
+    }
+
+    /// Builds a new mutable vector
+    pub fn vector(items: Vec<Object>) -> Object {
+        Object::Vector(Rc::new(Vector {
+            items: RefCell::new(items),
+            mutable: true,
+        }))
+    }
+
+    /// Builds a vector constant, as written in the source of a program
+    pub fn vector_literal(items: Vec<Object>) -> Object {
+        Object::Vector(Rc::new(Vector {
+            items: RefCell::new(items),
+            mutable: false,
+        }))
+    }
+
+    /// Builds a new mutable bytevector
+    pub fn bytevector(bytes: Vec<u8>) -> Object {
+        Object::Bytevector(Rc::new(Bytevector {
+            bytes: RefCell::new(bytes),
+            mutable: true,
+        }))
+    }
+
+    /// Builds a bytevector constant, as written in the source of a program
+    pub fn bytevector_literal(bytes: Vec<u8>) -> Object {
+        Object::Bytevector(Rc::new(Bytevector {
+            bytes: RefCell::new(bytes),
+            mutable: false,
+        }))
+    }
+
+    /// Builds the result of `(values ...)`, a single value standing for itself
+    pub fn values(mut values: Vec<Object>) -> Object {
+        if values.len() == 1 {
+            values.pop().unwrap()
+        } else {
+            Object::Values(Rc::new(values))
+        }
+    }
+
+    /// Returns the values an expression evaluated to, a single one unless it called `values`
+    pub fn values_to_vec(&self) -> Vec<Object> {
+        match self {
+            Object::Values(values) => values.to_vec(),
+            other => vec![other.clone()],
+        }
+    }
+
+    /// Builds a proper list from its elements
+    pub fn list(items: Vec<Object>) -> Object {
+        Object::list_with_tail(items, Object::Nil)
+    }
+
+    /// Builds a list from its elements, ending with `tail` instead of the empty list
+    pub fn list_with_tail(items: Vec<Object>, tail: Object) -> Object {
+        items
+            .into_iter()
+            .rev()
+            .fold(tail, |cdr, car| Object::cons(car, cdr))
+    }
+
+    /// Returns the elements of a proper list, or None if this is not one
+    pub fn list_to_vec(&self) -> Option<Vec<Object>> {
+        let mut items = Vec::new();
+        let mut current = self.clone();
+
+        loop {
+            current = match current {
+                Object::Nil => return Some(items),
+                Object::Pair(pair) => {
+                    items.push(pair.car());
+                    pair.cdr()
+                }
+                _ => return None,
+            }
+        }
+    }
+}
+
+/// The representation of an object by `display`, see `Object::display`
+pub struct Displayed<'a>(&'a Object);
+
+impl fmt::Display for Displayed<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.print(f, false)
+    }
 }
 
+/// Objects are printed as `write` does, so that the reader can give them back
 impl fmt::Display for Object {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.print(f, true)
+    }
+}
+
+/// Writes a string literal, escaping what the reader would not take verbatim
+pub fn write_string(f: &mut fmt::Formatter, s: &str) -> fmt::Result {
+    write!(f, "\"")?;
+
+    for c in s.chars() {
+        match c {
+            '"' => write!(f, "\\\"")?,
+            '\\' => write!(f, "\\\\")?,
+            '\n' => write!(f, "\\n")?,
+            '\t' => write!(f, "\\t")?,
+            '\r' => write!(f, "\\r")?,
+            '\u{7}' => write!(f, "\\a")?,
+            '\u{8}' => write!(f, "\\b")?,
+            c if c.is_control() => write!(f, "\\x{:x};", c as u32)?,
+            c => write!(f, "{}", c)?,
+        }
+    }
+
+    write!(f, "\"")
+}
+
+impl Object {
+    /// Returns a printable form of the object where strings and chars stand for themselves, as `display` does
+    pub fn display(&self) -> Displayed<'_> {
+        Displayed(self)
+    }
+
+    /// Prints the object, either as `write` does or as `display` does
+    fn print(&self, f: &mut fmt::Formatter, write: bool) -> fmt::Result {
         match self {
-            Object::Integer(n) => write!(f, "{}", n),
-            Object::Float(fl) => write!(f, "{}", fl),
+            Object::Integer(_)
+            | Object::BigInteger(_)
+            | Object::Rational(_)
+            | Object::Float(_)
+            | Object::Complex(_) => {
+                // numbers are printed so that the reader gives them back unchanged
+                write!(f, "{}", number::to_string(self, 10).unwrap())
+            }
             Object::Bool(b) => write!(f, "{}", if *b { "#t" } else { "#f" }),
-            Object::String(s) => write!(f, "\"{}\"", s),
+            Object::Char(c) if !write => write!(f, "{}", c),
+            Object::Char(c) => match CHAR_NAMES.iter().find(|(_, named)| named == c) {
+                Some((name, _)) => write!(f, "#\\{}", name),
+                None if c.is_control() => write!(f, "#\\x{:x}", *c as u32),
+                None => write!(f, "#\\{}", c),
+            },
+            Object::String(s) if !write => write!(f, "{}", s.chars.borrow()),
+            Object::String(s) => write_string(f, &s.chars.borrow()),
             Object::Symbol(s) => write!(f, "{}", s),
-            Object::Lambda(params, _) => {
-                write!(f, "lambda (")?;
+            Object::Lambda(lambda) => write!(f, "{}", lambda),
+            Object::CaseLambda(case_lambda) => {
+                write!(f, "case-lambda (")?;
 
-                for p in params {
-                    write!(f, "{}, ", p)?;
+                for clause in &case_lambda.clauses {
+                    write!(f, "{}, ", clause)?;
                 }
-                write!(f, ")")?;
-
-                Ok(())
+                write!(f, ")")
             }
-            Object::List(list) => {
+            Object::Native(native) => write!(f, "#<procedure {}>", native.name),
+            Object::Pair(pair) => {
                 write!(f, "(")?;
+                pair.car().print(f, write)?;
 
-                for (i, o) in list.iter().enumerate() {
+                let mut tail = pair.cdr();
+                loop {
+                    tail = match tail {
+                        Object::Nil => break,
+                        Object::Pair(pair) => {
+                            write!(f, " ")?;
+                            pair.car().print(f, write)?;
+                            pair.cdr()
+                        }
+                        other => {
+                            write!(f, " . ")?;
+                            other.print(f, write)?;
+                            break;
+                        }
+                    }
+                }
+
+                write!(f, ")")
+            }
+            Object::Vector(vector) => {
+                write!(f, "#(")?;
+
+                for (i, item) in vector.items.borrow().iter().enumerate() {
                     if i > 0 {
                         write!(f, " ")?;
                     }
-                    write!(f, "{}", o)?;
+                    item.print(f, write)?;
                 }
 
-                write!(f, ")")?;
+                write!(f, ")")
+            }
+            Object::Bytevector(bytevector) => {
+                write!(f, "#u8(")?;
+
+                for (i, byte) in bytevector.bytes.borrow().iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " ")?;
+                    }
+                    write!(f, "{}", byte)?;
+                }
+
+                write!(f, ")")
+            }
+            Object::Values(values) => {
+                for (i, value) in values.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, " ")?;
+                    }
+                    value.print(f, write)?;
+                }
 
                 Ok(())
             }
+            Object::Nil => write!(f, "()"),
+            Object::Unspecified => Ok(()),
+        }
+    }
+}
+
+/// A cons cell, whose two halves may be mutated in place
+#[derive(Debug, PartialEq)]
+pub struct Pair {
+    pub car: RefCell<Object>,
+    pub cdr: RefCell<Object>,
+}
+
+impl Pair {
+    pub fn car(&self) -> Object {
+        self.car.borrow().clone()
+    }
+
+    pub fn cdr(&self) -> Object {
+        self.cdr.borrow().clone()
+    }
+}
+
+/// The text of a string, shared by every reference to it so that `string-set!` is seen by all
+#[derive(Debug)]
+pub struct Str {
+    pub chars: RefCell<String>,
+    /// False for the strings written in the source of a program
+    pub mutable: bool,
+}
+
+impl PartialEq for Str {
+    // compares the text, regardless of identity
+    fn eq(&self, other: &Self) -> bool {
+        *self.chars.borrow() == *other.chars.borrow()
+    }
+}
+
+/// The elements of a vector
+#[derive(Debug)]
+pub struct Vector {
+    pub items: RefCell<Vec<Object>>,
+    /// False for `#(...)` literals
+    pub mutable: bool,
+}
+
+impl PartialEq for Vector {
+    // compares the elements pairwise
+    fn eq(&self, other: &Self) -> bool {
+        *self.items.borrow() == *other.items.borrow()
+    }
+}
+
+/// The bytes of a bytevector
+#[derive(Debug)]
+pub struct Bytevector {
+    pub bytes: RefCell<Vec<u8>>,
+    /// False for `#u8(...)` literals
+    pub mutable: bool,
+}
+
+impl PartialEq for Bytevector {
+    // compares the bytes
+    fn eq(&self, other: &Self) -> bool {
+        *self.bytes.borrow() == *other.bytes.borrow()
+    }
+}
+
+/// The variables bound by a lambda or a define-values: `args`, `(a b)` or `(a b . rest)`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Formals {
+    pub params: Vec<Symbol>,
+    /// The variable receiving the list of the values left after `params`, if any
+    pub rest: Option<Symbol>,
+}
+
+impl Formals {
+    pub fn arity(&self) -> Arity {
+        match self.rest {
+            Some(_) => Arity::AtLeast(self.params.len()),
+            None => Arity::Exactly(self.params.len()),
+        }
+    }
+}
+
+/// A closure: the parameters and body of a lambda, along with the environment it was created in
+pub struct Lambda {
+    pub formals: Formals,
+    /// The expressions of the body, evaluated in order
+    pub body: Vec<Object>,
+    pub env: EnvRef,
+}
+
+impl fmt::Display for Lambda {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "lambda (")?;
+
+        for p in &self.formals.params {
+            write!(f, "{}, ", p)?;
+        }
+        if let Some(rest) = self.formals.rest {
+            write!(f, ". {}", rest)?;
+        }
+
+        write!(f, ")")
+    }
+}
+
+impl PartialEq for Lambda {
+    // two closures are only equal if they are the same object
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+impl fmt::Debug for Lambda {
+    // the environment is left out, as it may contain the closure itself
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Lambda")
+            .field("formals", &self.formals)
+            .field("body", &self.body)
+            .finish()
+    }
+}
+
+/// A procedure made of several lambdas, the first one accepting the arguments being called
+#[derive(Debug)]
+pub struct CaseLambda {
+    pub clauses: Vec<Lambda>,
+}
+
+impl CaseLambda {
+    /// Fails with an error listing the accepted counts if no clause accepts `count` arguments
+    pub fn select(&self, count: usize) -> Result<&Lambda, String> {
+        match self
+            .clauses
+            .iter()
+            .find(|clause| clause.formals.arity().accepts(count))
+        {
+            Some(clause) => Ok(clause),
+            None if self.clauses.is_empty() => Err(format!(
+                "case-lambda without clauses cannot be called, but was given {} arguments",
+                count
+            )),
+            None => {
+                let arities: Vec<String> =
+                    self.merged_arities().iter().map(Arity::to_string).collect();
+
+                Err(format!(
+                    "case-lambda expects {} arguments, but was given {}",
+                    arities.join(" or "),
+                    count
+                ))
+            }
+        }
+    }
+
+    /// The counts of arguments accepted by the clauses, as disjoint ranges in increasing order
+    fn merged_arities(&self) -> Vec<Arity> {
+        // each range is (min, max), with None for no maximum
+        let mut ranges: Vec<(usize, Option<usize>)> = self
+            .clauses
+            .iter()
+            .map(|clause| match clause.formals.arity() {
+                Arity::Exactly(n) => (n, Some(n)),
+                Arity::AtLeast(min) => (min, None),
+                Arity::Between(min, max) => (min, Some(max)),
+            })
+            .collect();
+        ranges.sort();
+
+        let mut merged: Vec<(usize, Option<usize>)> = Vec::new();
+        for (min, max) in ranges {
+            match merged.last_mut() {
+                // the range overlaps or follows the last one
+                Some((_, last_max)) if last_max.is_none_or(|last_max| min <= last_max + 1) => {
+                    *last_max = last_max.zip(max).map(|(a, b)| a.max(b));
+                }
+                _ => merged.push((min, max)),
+            }
+        }
+
+        merged
+            .into_iter()
+            .map(|range| match range {
+                (min, None) => Arity::AtLeast(min),
+                (min, Some(max)) if min == max => Arity::Exactly(min),
+                (min, Some(max)) => Arity::Between(min, max),
+            })
+            .collect()
+    }
+}
+
+impl PartialEq for CaseLambda {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+/// A procedure implemented in Rust
+#[derive(Debug)]
+pub struct Native {
+    pub name: &'static str,
+    pub arity: Arity,
+    pub func: fn(&[Object]) -> Result<Object, String>,
+}
+
+impl PartialEq for Native {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+/// The number of arguments a procedure accepts
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Arity {
+    Exactly(usize),
+    AtLeast(usize),
+    Between(usize, usize),
+}
+
+impl Arity {
+    pub fn accepts(&self, count: usize) -> bool {
+        match *self {
+            Arity::Exactly(n) => count == n,
+            Arity::AtLeast(min) => count >= min,
+            Arity::Between(min, max) => (min..=max).contains(&count),
+        }
+    }
+
+    /// Fails with a descriptive error if `name` cannot be called with `count` arguments
+    pub fn check(&self, name: &str, count: usize) -> Result<(), String> {
+        if self.accepts(count) {
+            Ok(())
+        } else {
+            Err(format!(
+                "{} expects {} arguments, but was given {}",
+                name, self, count
+            ))
+        }
+    }
+}
+
+impl fmt::Display for Arity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Arity::Exactly(n) => write!(f, "{}", n),
+            Arity::AtLeast(min) => write!(f, "at least {}", min),
+            Arity::Between(min, max) => write!(f, "between {} and {}", min, max),
         }
     }
 }
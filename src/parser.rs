@@ -1,5 +1,6 @@
 use crate::lexer::{tokenize, Token, TokenIterator};
 use crate::object::Object;
+use crate::symbol::Symbol;
 
 use std::fmt;
 
@@ -22,40 +23,90 @@ impl fmt::Display for ParseError {
     }
 }
 
-pub fn parse(s: &str) -> Result<Object, ParseError> {
+/// Parses every top-level datum of a program, in order
+pub fn parse_program(s: &str) -> Result<Vec<Object>, ParseError> {
     let mut tokens = tokenize(s);
+    let mut program = Vec::new();
 
-    // parse first token outside of loop
-    if let Some(t) = tokens.next() {
+    while let Some(t) = next_token(&mut tokens)? {
+        program.push(parse_datum(t, &mut tokens)?);
+    }
+
+    Ok(program)
+}
+
+/// Returns the next token, skipping the datums commented out with `#;`
+fn next_token(tokens: &mut TokenIterator) -> Result<Option<Token>, ParseError> {
+    while let Some(t) = tokens.next() {
         match t {
-            Token::Integer(n) => Ok(Object::Integer(n)),
-            Token::Float(f) => Ok(Object::Float(f)),
-            Token::String(s) => Ok(Object::String(s)),
-            Token::Symbol(s) => Ok(Object::Symbol(s)),
-            Token::LParen => parse_list(&mut tokens),
-            Token::RParen => Err(ParseError::new("Unauthorized token: )")),
-            Token::LexerError(e) => Err(ParseError::new(&e)),
+            Token::DatumComment => match next_token(tokens)? {
+                Some(t) => {
+                    parse_datum(t, tokens)?;
+                }
+                None => return Err(ParseError::new("Encountered an unexpected EOF !")),
+            },
+            t => return Ok(Some(t)),
         }
-    } else {
-        Err(ParseError::new(""))
+    }
+
+    Ok(None)
+}
+
+fn parse_datum(t: Token, tokens: &mut TokenIterator) -> Result<Object, ParseError> {
+    match t {
+        Token::Number(n) => Ok(n),
+        Token::Bool(b) => Ok(Object::Bool(b)),
+        Token::Char(c) => Ok(Object::Char(c)),
+        Token::String(s) => Ok(Object::string_literal(s)),
+        Token::Symbol(s) => Ok(Object::symbol(&s)),
+        Token::LParen => parse_list(tokens),
+        Token::VectorStart => parse_vector(tokens),
+        Token::BytevectorStart => parse_bytevector(tokens),
+        Token::RParen => Err(ParseError::new("Unauthorized token: )")),
+        Token::Dot => Err(ParseError::new("Unauthorized token: .")),
+        Token::Quote => parse_abbreviation(Symbol::QUOTE, tokens),
+        Token::Quasiquote => parse_abbreviation(Symbol::QUASIQUOTE, tokens),
+        Token::Unquote => parse_abbreviation(Symbol::UNQUOTE, tokens),
+        Token::UnquoteSplicing => parse_abbreviation(Symbol::UNQUOTE_SPLICING, tokens),
+        Token::DatumComment => unreachable!("Datum comments are skipped by next_token"),
+        Token::LexerError(e) => Err(ParseError::new(&e)),
+    }
+}
+
+/// Expands `'x` and its siblings into their long form, `(quote x)`
+fn parse_abbreviation(keyword: Symbol, tokens: &mut TokenIterator) -> Result<Object, ParseError> {
+    match next_token(tokens)? {
+        Some(t) => Ok(Object::list(vec![
+            Object::Symbol(keyword),
+            parse_datum(t, tokens)?,
+        ])),
+        None => Err(ParseError::new("Encountered an unexpected EOF !")),
     }
 }
 
 fn parse_list(tokens: &mut TokenIterator) -> Result<Object, ParseError> {
     let mut list: Vec<Object> = Vec::new();
 
-    while let Some(t) = tokens.next() {
+    while let Some(t) = next_token(tokens)? {
         match t {
-            Token::Integer(n) => list.push(Object::Integer(n)),
-            Token::Float(f) => list.push(Object::Float(f)),
-            Token::String(s) => list.push(Object::String(s)),
-            Token::Symbol(s) => list.push(Object::Symbol(s)),
-            Token::LParen => {
-                let sub_list = parse_list(tokens)?;
-                list.push(sub_list);
+            Token::RParen => return Ok(Object::list(list)),
+            Token::Dot if !list.is_empty() => {
+                // a dotted list: exactly one datum is expected before the closing paren
+                let tail = match next_token(tokens)? {
+                    Some(t) => parse_datum(t, tokens)?,
+                    None => break,
+                };
+
+                return match next_token(tokens)? {
+                    Some(Token::RParen) => Ok(Object::list_with_tail(list, tail)),
+                    Some(t) => Err(ParseError::new(&format!(
+                        "Expected ) after the tail of a dotted list, found {}",
+                        t
+                    ))),
+                    None => break,
+                };
             }
-            Token::RParen => return Ok(Object::List(list)),
-            Token::LexerError(e) => return Err(ParseError::new(&e)),
+            t => list.push(parse_datum(t, tokens)?),
         }
     }
 
@@ -63,6 +114,38 @@ fn parse_list(tokens: &mut TokenIterator) -> Result<Object, ParseError> {
     Err(ParseError::new("Encountered an unexpected EOF !"))
 }
 
+fn parse_vector(tokens: &mut TokenIterator) -> Result<Object, ParseError> {
+    let mut items: Vec<Object> = Vec::new();
+
+    while let Some(t) = next_token(tokens)? {
+        match t {
+            Token::RParen => return Ok(Object::vector_literal(items)),
+            t => items.push(parse_datum(t, tokens)?),
+        }
+    }
+
+    Err(ParseError::new("Encountered an unexpected EOF !"))
+}
+
+fn parse_bytevector(tokens: &mut TokenIterator) -> Result<Object, ParseError> {
+    let mut bytes: Vec<u8> = Vec::new();
+
+    while let Some(t) = next_token(tokens)? {
+        match t {
+            Token::RParen => return Ok(Object::bytevector_literal(bytes)),
+            Token::Number(Object::Integer(n)) if (0..=255).contains(&n) => bytes.push(n as u8),
+            t => {
+                return Err(ParseError::new(&format!(
+                    "Invalid byte in bytevector: {}",
+                    t
+                )))
+            }
+        }
+    }
+
+    Err(ParseError::new("Encountered an unexpected EOF !"))
+}
+
 #[cfg(test)]
 mod test {
 
@@ -70,39 +153,36 @@ mod test {
 
     #[test]
     fn parse_integer() {
-        let list = parse("(1)").unwrap();
+        let list = parse_program("(1)").unwrap();
 
-        assert_eq!(list, Object::List(vec![Object::Integer(1)]))
+        assert_eq!(list, vec![Object::list(vec![Object::Integer(1)])])
     }
 
     #[test]
     fn parse_float() {
-        let list = parse("(1.512)").unwrap();
+        let list = parse_program("(1.512)").unwrap();
 
-        assert_eq!(list, Object::List(vec![Object::Float(1.512)]))
+        assert_eq!(list, vec![Object::list(vec![Object::Float(1.512)])])
     }
 
     #[test]
     fn parse_string() {
-        let list = parse("(\"Hello\")").unwrap();
+        let list = parse_program("(\"Hello\")").unwrap();
 
-        assert_eq!(
-            list,
-            Object::List(vec![Object::String("Hello".to_string())])
-        )
+        assert_eq!(list, vec![Object::list(vec![Object::string("Hello")])])
     }
 
     #[test]
     fn parse_add() {
-        let list = parse("(+ 1 2)").unwrap();
+        let list = parse_program("(+ 1 2)").unwrap();
 
         assert_eq!(
             list,
-            Object::List(vec![
-                Object::Symbol("+".to_string()),
+            vec![Object::list(vec![
+                Object::symbol("+"),
                 Object::Integer(1),
                 Object::Integer(2)
-            ])
+            ])]
         )
     }
 
@@ -113,30 +193,290 @@ mod test {
                          (define pi 314)
                          (* pi (* r r))
                        )";
-        let list = parse(program).unwrap();
+        let list = parse_program(program).unwrap();
         assert_eq!(
             list,
-            Object::List(vec![
-                Object::List(vec![
-                    Object::Symbol("define".to_string()),
-                    Object::Symbol("r".to_string()),
+            vec![Object::list(vec![
+                Object::list(vec![
+                    Object::symbol("define"),
+                    Object::symbol("r"),
                     Object::Integer(10),
                 ]),
-                Object::List(vec![
-                    Object::Symbol("define".to_string()),
-                    Object::Symbol("pi".to_string()),
+                Object::list(vec![
+                    Object::symbol("define"),
+                    Object::symbol("pi"),
                     Object::Integer(314),
                 ]),
-                Object::List(vec![
-                    Object::Symbol("*".to_string()),
-                    Object::Symbol("pi".to_string()),
-                    Object::List(vec![
-                        Object::Symbol("*".to_string()),
-                        Object::Symbol("r".to_string()),
-                        Object::Symbol("r".to_string()),
+                Object::list(vec![
+                    Object::symbol("*"),
+                    Object::symbol("pi"),
+                    Object::list(vec![
+                        Object::symbol("*"),
+                        Object::symbol("r"),
+                        Object::symbol("r"),
                     ]),
                 ]),
-            ])
+            ])]
         );
     }
+
+    #[test]
+    fn parse_multiple_top_level_forms() {
+        let program = "(define a 1) (define b 2) a";
+        let list = parse_program(program).unwrap();
+        assert_eq!(
+            list,
+            vec![
+                Object::list(vec![
+                    Object::symbol("define"),
+                    Object::symbol("a"),
+                    Object::Integer(1),
+                ]),
+                Object::list(vec![
+                    Object::symbol("define"),
+                    Object::symbol("b"),
+                    Object::Integer(2),
+                ]),
+                Object::symbol("a"),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_empty_program() {
+        assert_eq!(parse_program("  ").unwrap(), vec![]);
+    }
+
+    #[test]
+    fn parse_unclosed_list_errors() {
+        assert!(parse_program("(define a 1) (+ a").is_err());
+    }
+
+    #[test]
+    fn parse_booleans() {
+        let list = parse_program("(#t #f #true #false)").unwrap();
+
+        assert_eq!(
+            list,
+            vec![Object::list(vec![
+                Object::Bool(true),
+                Object::Bool(false),
+                Object::Bool(true),
+                Object::Bool(false),
+            ])]
+        )
+    }
+
+    #[test]
+    fn booleans_round_trip() {
+        for source in ["#t", "#f", "(#t #f)"] {
+            let parsed = parse_program(source).unwrap();
+            let printed = format!("{}", parsed[0]);
+
+            assert_eq!(printed, source);
+            assert_eq!(parse_program(&printed).unwrap(), parsed);
+        }
+    }
+
+    #[test]
+    fn parse_with_comments() {
+        let program = "
+            ; the radius
+            (define r 10) #| not
+            (define r 20) |#
+            (* r #;(ignored datum) r)
+        ";
+        let list = parse_program(program).unwrap();
+
+        assert_eq!(
+            list,
+            vec![
+                Object::list(vec![
+                    Object::symbol("define"),
+                    Object::symbol("r"),
+                    Object::Integer(10),
+                ]),
+                Object::list(vec![
+                    Object::symbol("*"),
+                    Object::symbol("r"),
+                    Object::symbol("r"),
+                ]),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_datum_comment_at_end_of_list() {
+        let list = parse_program("(a #;b #;#;c d)").unwrap();
+
+        assert_eq!(list, vec![Object::list(vec![Object::symbol("a")])]);
+    }
+
+    #[test]
+    fn parse_datum_comment_without_datum_errors() {
+        assert!(parse_program("(a #;)").is_err());
+        assert!(parse_program("#;").is_err());
+    }
+
+    #[test]
+    fn parse_quote_abbreviations() {
+        let list = parse_program("'a `(b ,c ,@d)").unwrap();
+
+        assert_eq!(
+            list,
+            vec![
+                Object::list(vec![Object::symbol("quote"), Object::symbol("a"),]),
+                Object::list(vec![
+                    Object::symbol("quasiquote"),
+                    Object::list(vec![
+                        Object::symbol("b"),
+                        Object::list(vec![Object::symbol("unquote"), Object::symbol("c"),]),
+                        Object::list(vec![
+                            Object::symbol("unquote-splicing"),
+                            Object::symbol("d"),
+                        ]),
+                    ]),
+                ]),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_quote_without_datum_errors() {
+        assert!(parse_program("'").is_err());
+        assert!(parse_program("(a ')").is_err());
+    }
+
+    #[test]
+    fn parse_dotted_pair() {
+        let list = parse_program("(1 . 2) (1 2 . 3) (1 . (2 . ()))").unwrap();
+
+        assert_eq!(
+            list,
+            vec![
+                Object::cons(Object::Integer(1), Object::Integer(2)),
+                Object::list_with_tail(
+                    vec![Object::Integer(1), Object::Integer(2)],
+                    Object::Integer(3)
+                ),
+                Object::list(vec![Object::Integer(1), Object::Integer(2)]),
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_empty_list() {
+        assert_eq!(parse_program("()").unwrap(), vec![Object::Nil]);
+    }
+
+    #[test]
+    fn parse_invalid_dotted_list_errors() {
+        assert!(parse_program("( . 1)").is_err());
+        assert!(parse_program("(1 . )").is_err());
+        assert!(parse_program("(1 . 2 3)").is_err());
+        assert!(parse_program("(1 . 2").is_err());
+        assert!(parse_program(".").is_err());
+    }
+
+    #[test]
+    fn improper_lists_round_trip() {
+        for source in ["(1 . 2)", "(a b . c)", "((a . b) (c . d))", "()"] {
+            let parsed = parse_program(source).unwrap();
+            let printed = format!("{}", parsed[0]);
+
+            assert_eq!(printed, source);
+            assert_eq!(parse_program(&printed).unwrap(), parsed);
+        }
+    }
+
+    #[test]
+    fn chars_round_trip() {
+        for source in [
+            "#\\a",
+            "#\\space",
+            "#\\newline",
+            "#\\λ",
+            "#\\(",
+            "#\\x7",
+            "(#\\a #\\b)",
+        ] {
+            let parsed = parse_program(source).unwrap();
+            let printed = format!("{}", parsed[0]);
+
+            assert_eq!(parse_program(&printed).unwrap(), parsed);
+        }
+
+        assert_eq!(
+            format!("{}", parse_program("#\\x20").unwrap()[0]),
+            "#\\space"
+        );
+        assert_eq!(
+            format!("{}", parse_program("#\\alarm").unwrap()[0]),
+            "#\\alarm"
+        );
+    }
+
+    #[test]
+    fn strings_round_trip() {
+        for source in [r#""a\"b""#, r#""\\""#, r#""line\nnext\ttab""#, r#""\x1b;""#] {
+            let parsed = parse_program(source).unwrap();
+            let printed = format!("{}", parsed[0]);
+
+            assert_eq!(printed, source);
+            assert_eq!(parse_program(&printed).unwrap(), parsed);
+        }
+    }
+
+    #[test]
+    fn display_does_not_escape() {
+        let parsed = parse_program(r#"("a\"b" #\c (#\space "\n"))"#).unwrap();
+
+        assert_eq!(format!("{}", parsed[0].display()), "(a\"b c (  \n))");
+        assert_eq!(format!("{}", parsed[0]), r#"("a\"b" #\c (#\space "\n"))"#);
+    }
+
+    #[test]
+    fn vectors_round_trip() {
+        for source in ["#()", "#(1 2 3)", "#(a #(\"b\" #\\c) (d . e))"] {
+            let parsed = parse_program(source).unwrap();
+
+            assert_eq!(format!("{}", parsed[0]), source);
+        }
+
+        assert_eq!(
+            parse_program("#(1 'a)").unwrap(),
+            vec![Object::vector(vec![
+                Object::Integer(1),
+                Object::list(vec![Object::symbol("quote"), Object::symbol("a")])
+            ])]
+        );
+    }
+
+    #[test]
+    fn dotted_vector_is_error() {
+        assert!(parse_program("#(1 . 2)").is_err());
+        assert!(parse_program("#(1 2").is_err());
+    }
+
+    #[test]
+    fn bytevectors_round_trip() {
+        for source in ["#u8()", "#u8(0 1 255)"] {
+            let parsed = parse_program(source).unwrap();
+
+            assert_eq!(format!("{}", parsed[0]), source);
+        }
+
+        assert_eq!(
+            parse_program("#u8(#xff #;1 2)").unwrap(),
+            vec![Object::bytevector(vec![255, 2])]
+        );
+    }
+
+    #[test]
+    fn invalid_byte_is_error() {
+        assert!(parse_program("#u8(256)").is_err());
+        assert!(parse_program("#u8(-1)").is_err());
+        assert!(parse_program("#u8(1.0)").is_err());
+        assert!(parse_program("#u8(a)").is_err());
+    }
 }
@@ -0,0 +1,159 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+
+/// An interned symbol: two symbols with the same name are the same handle,
+/// so that comparing and hashing them never looks at their name
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Symbol(u32);
+
+/// The keywords of the special forms, interned first so that they can be matched as constants.
+/// Their order must be the one of the constants below
+const KEYWORDS: [&str; 17] = [
+    "quote",
+    "quasiquote",
+    "unquote",
+    "unquote-splicing",
+    "define",
+    "if",
+    "lambda",
+    "begin",
+    "case-lambda",
+    "define-values",
+    "set!",
+    "let",
+    "let*",
+    "letrec",
+    "letrec*",
+    "let-values",
+    "let*-values",
+];
+
+impl Symbol {
+    pub const QUOTE: Symbol = Symbol(0);
+    pub const QUASIQUOTE: Symbol = Symbol(1);
+    pub const UNQUOTE: Symbol = Symbol(2);
+    pub const UNQUOTE_SPLICING: Symbol = Symbol(3);
+    pub const DEFINE: Symbol = Symbol(4);
+    pub const IF: Symbol = Symbol(5);
+    pub const LAMBDA: Symbol = Symbol(6);
+    pub const BEGIN: Symbol = Symbol(7);
+    pub const CASE_LAMBDA: Symbol = Symbol(8);
+    pub const DEFINE_VALUES: Symbol = Symbol(9);
+    pub const SET: Symbol = Symbol(10);
+    pub const LET: Symbol = Symbol(11);
+    pub const LET_STAR: Symbol = Symbol(12);
+    pub const LETREC: Symbol = Symbol(13);
+    pub const LETREC_STAR: Symbol = Symbol(14);
+    pub const LET_VALUES: Symbol = Symbol(15);
+    pub const LET_STAR_VALUES: Symbol = Symbol(16);
+
+    /// Returns the symbol named `name`, creating it the first time
+    pub fn intern(name: &str) -> Symbol {
+        TABLE.with(|table| table.borrow_mut().intern(name))
+    }
+
+    /// Creates a symbol that is different from every other one, even those with the same name
+    pub fn uninterned(prefix: &str) -> Symbol {
+        TABLE.with(|table| {
+            let mut table = table.borrow_mut();
+            let symbol = Symbol(table.names.len() as u32);
+
+            let name = format!("{}{}", prefix, symbol.0);
+            table.names.push(Rc::from(name));
+            symbol
+        })
+    }
+
+    pub fn name(&self) -> Rc<str> {
+        TABLE.with(|table| table.borrow().names[self.0 as usize].clone())
+    }
+}
+
+impl fmt::Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+struct Table {
+    /// The name of every symbol, indexed by its id
+    names: Vec<Rc<str>>,
+    /// The interned symbols, uninterned ones are left out
+    ids: HashMap<Rc<str>, Symbol>,
+}
+
+impl Table {
+    fn new() -> Table {
+        let mut table = Table {
+            names: Vec::new(),
+            ids: HashMap::new(),
+        };
+
+        for keyword in KEYWORDS {
+            table.intern(keyword);
+        }
+
+        table
+    }
+
+    fn intern(&mut self, name: &str) -> Symbol {
+        if let Some(symbol) = self.ids.get(name) {
+            return *symbol;
+        }
+
+        let symbol = Symbol(self.names.len() as u32);
+        let name: Rc<str> = Rc::from(name);
+
+        self.names.push(name.clone());
+        self.ids.insert(name, symbol);
+        symbol
+    }
+}
+
+thread_local! {
+    static TABLE: RefCell<Table> = RefCell::new(Table::new());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keywords_match_their_constants() {
+        assert_eq!(Symbol::intern("quote"), Symbol::QUOTE);
+        assert_eq!(Symbol::intern("quasiquote"), Symbol::QUASIQUOTE);
+        assert_eq!(Symbol::intern("unquote"), Symbol::UNQUOTE);
+        assert_eq!(Symbol::intern("unquote-splicing"), Symbol::UNQUOTE_SPLICING);
+        assert_eq!(Symbol::intern("define"), Symbol::DEFINE);
+        assert_eq!(Symbol::intern("if"), Symbol::IF);
+        assert_eq!(Symbol::intern("lambda"), Symbol::LAMBDA);
+        assert_eq!(Symbol::intern("begin"), Symbol::BEGIN);
+        assert_eq!(Symbol::intern("case-lambda"), Symbol::CASE_LAMBDA);
+        assert_eq!(Symbol::intern("define-values"), Symbol::DEFINE_VALUES);
+        assert_eq!(Symbol::intern("set!"), Symbol::SET);
+        assert_eq!(Symbol::intern("let"), Symbol::LET);
+        assert_eq!(Symbol::intern("let*"), Symbol::LET_STAR);
+        assert_eq!(Symbol::intern("letrec"), Symbol::LETREC);
+        assert_eq!(Symbol::intern("letrec*"), Symbol::LETREC_STAR);
+        assert_eq!(Symbol::intern("let-values"), Symbol::LET_VALUES);
+        assert_eq!(Symbol::intern("let*-values"), Symbol::LET_STAR_VALUES);
+    }
+
+    #[test]
+    fn symbols_are_interned() {
+        assert_eq!(Symbol::intern("foo"), Symbol::intern("foo"));
+        assert_ne!(Symbol::intern("foo"), Symbol::intern("bar"));
+        assert_eq!(&*Symbol::intern("foo").name(), "foo");
+    }
+
+    #[test]
+    fn uninterned_symbols_are_unique() {
+        let g = Symbol::uninterned("g");
+
+        assert_ne!(g, Symbol::uninterned("g"));
+        assert_ne!(g, Symbol::intern(&g.name()));
+        assert!(g.name().starts_with('g'));
+    }
+}